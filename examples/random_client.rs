@@ -1,3 +1,4 @@
+#[cfg(feature = "random")]
 use vintagestory_mod_db_api::VintageStoryModDbApi;
 
 #[cfg(feature = "random")]
@@ -16,7 +17,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Get random author
     let author = api.get_random_author().await?;
-    println!("Random author: {:?}", author.name.unwrap_or_else(|| "<Unnamed>".to_string()));
+    println!("Random author: {:?}", author.display_name());
 
     // Get random game version
     let version = api.get_random_game_version().await?;
@@ -1,60 +1,537 @@
 //! API client implementation for the VintageStory Web Mod API.
 use std::fmt::{Display, Formatter};
-use crate::{error::ApiError, models::*};
-use reqwest::Client;
+use std::future::Future;
+use std::str::FromStr;
+use crate::{error::ApiError, models::*, retry::RetryPolicy, transport::{Transport, TransportResponse}};
+#[cfg(feature = "reqwest")]
+use crate::transport::ReqwestTransport;
+use futures::stream::{self, StreamExt, TryStreamExt};
+#[cfg(feature = "reqwest")]
+use reqwest::{header::{CONTENT_RANGE, RANGE, RETRY_AFTER}, StatusCode};
+use serde::de::DeserializeOwned;
+use unicode_normalization::UnicodeNormalization;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use std::path::Path;
+#[cfg(feature = "reqwest")]
+use std::path::PathBuf;
+#[cfg(feature = "reqwest")]
+use tokio::{fs::{File, OpenOptions}, io::AsyncWriteExt};
+
+#[cfg(feature = "reqwest")]
+type DefaultTransport = ReqwestTransport;
+#[cfg(not(feature = "reqwest"))]
+type DefaultTransport = crate::transport::NoTransport;
 
 /// The core API client for interacting with the VintageStory mod database.
+///
+/// Requests are sent through a [`Transport`], which defaults to [`ReqwestTransport`]
+/// when the `reqwest` feature is enabled.
 #[derive(Debug)]
-pub struct VintageStoryModDbApi {
-    client: Client,
+pub struct VintageStoryModDbApi<T = DefaultTransport> {
+    transport: T,
+    base_url: String,
     enable_cache: bool,
+    asset_host: String,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    cache_ttl: Option<Duration>,
+    retry_policy: RetryPolicy,
+    rate_limiter: Option<RateLimiter>,
+    user_agent: Option<String>,
 
-    mods_cache: Mutex<Option<Vec<SimpleMod>>>,
-    authors_cache: Mutex<Option<Vec<Author>>>,
+    mods_cache: Mutex<Option<ModsCache>>,
+    authors_cache: Mutex<Option<ListCache<Author>>>,
+    tags_cache: Mutex<Option<ListCache<Tag>>>,
+    game_versions_cache: Mutex<Option<ListCache<GameVersion>>>,
+    mods_detail_cache: Mutex<ModDetailCache>,
 }
 
-impl VintageStoryModDbApi {
-    const BASE_URL: &'static str = "https://mods.vintagestory.at/api";
+/// Effective configuration of a client, returned by [`VintageStoryModDbApi::config`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct ApiConfig {
+    /// Base URL API requests are sent to
+    pub base_url: String,
+    /// Host asset URLs are built against
+    pub asset_host: String,
+    /// Whether `/mods`, `/mod/{id}`, `/authors`, `/tags` and `/gameversions` results are cached
+    pub enable_cache: bool,
+    /// Overall request timeout, if set through the builder. `None` when using a custom `reqwest::Client`
+    pub timeout: Option<Duration>,
+    /// Connection timeout, if set through the builder
+    pub connect_timeout: Option<Duration>,
+    /// How long cached results stay fresh, `None` if they never expire
+    pub cache_ttl: Option<Duration>,
+    /// How transient failures are retried
+    pub retry_policy: RetryPolicy,
+    /// Most requests sent per second, `None` if unlimited
+    pub requests_per_second: Option<f64>,
+    /// `User-Agent` header set by the builder. `None` when using a custom client or transport
+    pub user_agent: Option<String>,
+}
 
+#[cfg(feature = "reqwest")]
+impl VintageStoryModDbApi {
     /// Create a new API client instance.
     ///
-    /// If `enable_cache` is true, results from `/mods` and `/authors` will be cached in memory. Recommended if you will be making several calls with the same client
+    /// If `enable_cache` is true, results from `/mods`, `/mod/{id}`, `/authors`, `/tags` and `/gameversions` will be cached in memory. Recommended if you will be making several calls with the same client
+    ///
+    /// Requests time out after 30 seconds, use [`Self::builder`] to change that.
+    /// Panics if the HTTP client can't be initialized, like `reqwest::Client::new`.
     pub fn new(enable_cache: bool) -> Self {
+        Self::builder().enable_cache(enable_cache).build().expect("failed to build the default HTTP client")
+    }
+
+    /// Create a new API client instance that sends requests to `base_url` instead of `https://mods.vintagestory.at/api`,
+    /// e.g. a mirror or a local mock server.
+    ///
+    /// `enable_cache` behaves the same as in [`VintageStoryModDbApi::new`].
+    pub fn with_base_url(base_url: impl Into<String>, enable_cache: bool) -> Self {
+        Self::builder().base_url(base_url).enable_cache(enable_cache).build().expect("failed to build the default HTTP client")
+    }
+
+    /// Create a new API client instance that sends requests through an existing `reqwest::Client`,
+    /// e.g. one shared across your program with its own TLS, proxy and pool settings.
+    ///
+    /// The client's own timeouts apply. `enable_cache` behaves the same as in [`VintageStoryModDbApi::new`].
+    pub fn with_client(client: reqwest::Client, enable_cache: bool) -> Self {
+        Self::with_transport(ReqwestTransport::new(client), enable_cache)
+    }
+
+    /// Start configuring a client with a [`VintageStoryModDbApiBuilder`].
+    pub fn builder() -> VintageStoryModDbApiBuilder {
+        VintageStoryModDbApiBuilder::default()
+    }
+}
+
+/// Configures and builds a [`VintageStoryModDbApi`] using the reqwest transport.
+///
+/// ```no_run
+/// # use std::time::Duration;
+/// # use vintagestory_mod_db_api::VintageStoryModDbApi;
+/// let api = VintageStoryModDbApi::builder()
+///     .timeout(Duration::from_secs(10))
+///     .enable_cache(true)
+///     .build()?;
+/// # Ok::<(), vintagestory_mod_db_api::ApiError>(())
+/// ```
+#[cfg(feature = "reqwest")]
+#[derive(Debug, Clone)]
+pub struct VintageStoryModDbApiBuilder {
+    base_url: Option<String>,
+    asset_host: Option<String>,
+    enable_cache: bool,
+    timeout: Duration,
+    connect_timeout: Option<Duration>,
+    cache_ttl: Option<Duration>,
+    retry_policy: RetryPolicy,
+    requests_per_second: Option<f64>,
+    user_agent: String,
+    client: Option<reqwest::Client>,
+}
+
+#[cfg(feature = "reqwest")]
+impl Default for VintageStoryModDbApiBuilder {
+    fn default() -> Self {
+        Self {
+            base_url: None,
+            asset_host: None,
+            enable_cache: false,
+            timeout: Self::DEFAULT_TIMEOUT,
+            connect_timeout: None,
+            cache_ttl: None,
+            retry_policy: RetryPolicy::default(),
+            requests_per_second: None,
+            user_agent: crate::transport::DEFAULT_USER_AGENT.to_string(),
+            client: None,
+        }
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl VintageStoryModDbApiBuilder {
+    const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+    /// Send requests to `base_url` instead of `https://mods.vintagestory.at/api`.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Build asset URLs against `host`, see [`VintageStoryModDbApi::with_asset_host`].
+    pub fn asset_host(mut self, host: impl Into<String>) -> Self {
+        self.asset_host = Some(host.into());
+        self
+    }
+
+    /// Cache results from `/mods`, `/mod/{id}`, `/authors`, `/tags` and `/gameversions` in memory. Off by default.
+    pub fn enable_cache(mut self, enable_cache: bool) -> Self {
+        self.enable_cache = enable_cache;
+        self
+    }
+
+    /// Expire cached results after `ttl`, see [`VintageStoryModDbApi::with_cache_ttl`]. By default they never expire.
+    pub fn cache_ttl(mut self, ttl: Duration) -> Self {
+        self.cache_ttl = Some(ttl);
+        self
+    }
+
+    /// Retry transient failures according to `policy`. By default requests aren't retried.
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Send at most `requests_per_second` requests, see [`VintageStoryModDbApi::with_rate_limit`]. Unlimited by default.
+    pub fn requests_per_second(mut self, requests_per_second: f64) -> Self {
+        self.requests_per_second = Some(requests_per_second);
+        self
+    }
+
+    /// Timeout for a whole request, from connecting until the body is read. Defaults to 30 seconds.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Timeout for establishing a connection. Unset by default, so only the overall timeout applies.
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    /// Send `user_agent` as the `User-Agent` header. Defaults to `vintagestory_mod_db_api/<version>`.
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    /// Send requests through an existing `reqwest::Client`, see [`VintageStoryModDbApi::with_client`].
+    ///
+    /// The client is used as is, so [`Self::timeout`], [`Self::connect_timeout`] and [`Self::user_agent`] are ignored.
+    pub fn client(mut self, client: reqwest::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Build the client.
+    ///
+    /// Returns `ApiError::Unexpected` if the underlying HTTP client can't be built.
+    pub fn build(mut self) -> Result<VintageStoryModDbApi, ApiError> {
+        let (client, custom_client) = match self.client.take() {
+            Some(client) => (client, true),
+            None => {
+                let mut client = reqwest::Client::builder().timeout(self.timeout).user_agent(&self.user_agent);
+                if let Some(connect_timeout) = self.connect_timeout {
+                    client = client.connect_timeout(connect_timeout);
+                }
+                (client.build().map_err(Self::client_error)?, false)
+            }
+        };
+        Ok(self.configure(ReqwestTransport::new(client), custom_client))
+    }
+
+    /// Build a [`VintageStoryModDbApiBlocking`](crate::VintageStoryModDbApiBlocking) with the same settings,
+    /// sending requests through a `reqwest::blocking::Client`.
+    ///
+    /// Returns `ApiError::Unexpected` if an async client was passed to [`Self::client`], or the HTTP client can't be built.
+    #[cfg(feature = "blocking")]
+    pub fn build_blocking(self) -> Result<crate::VintageStoryModDbApiBlocking, ApiError> {
+        if self.client.is_some() {
+            return Err(ApiError::Unexpected("The blocking client can't use an async reqwest::Client".to_string()));
+        }
+        let mut client = reqwest::blocking::Client::builder().timeout(self.timeout).user_agent(&self.user_agent);
+        if let Some(connect_timeout) = self.connect_timeout {
+            client = client.connect_timeout(connect_timeout);
+        }
+        let transport = crate::blocking::BlockingTransport::new(client.build().map_err(Self::client_error)?);
+        Ok(crate::VintageStoryModDbApiBlocking::from_inner(self.configure(transport, false)))
+    }
+
+    fn client_error(e: reqwest::Error) -> ApiError {
+        ApiError::Unexpected(format!("Failed to build HTTP client: {}", e))
+    }
+
+    /// Apply every setting but the HTTP client itself to a client sending requests through `transport`.
+    fn configure<T: Transport>(self, transport: T, custom_client: bool) -> VintageStoryModDbApi<T> {
+        let mut api = VintageStoryModDbApi::with_transport(transport, self.enable_cache);
+        if let Some(base_url) = self.base_url {
+            api.set_base_url(base_url);
+        }
+        if let Some(host) = self.asset_host {
+            api = api.with_asset_host(host);
+        }
+        if !custom_client {
+            api.timeout = Some(self.timeout);
+            api.connect_timeout = self.connect_timeout;
+            api.user_agent = Some(self.user_agent);
+        }
+        api.cache_ttl = self.cache_ttl;
+        api.retry_policy = self.retry_policy;
+        if let Some(requests_per_second) = self.requests_per_second {
+            api = api.with_rate_limit(requests_per_second);
+        }
+        api
+    }
+}
+
+impl<T: Transport> VintageStoryModDbApi<T> {
+    const DEFAULT_BASE_URL: &'static str = "https://mods.vintagestory.at/api";
+    const DEFAULT_ASSET_HOST: &'static str = crate::models::ASSET_HOST;
+
+    /// Create a new API client instance that sends requests through a custom transport.
+    ///
+    /// `enable_cache` behaves the same as in [`VintageStoryModDbApi::new`].
+    pub fn with_transport(transport: T, enable_cache: bool) -> Self {
         Self {
-            client: Client::new(),
+            transport,
+            base_url: Self::DEFAULT_BASE_URL.to_string(),
             enable_cache,
+            asset_host: Self::DEFAULT_ASSET_HOST.to_string(),
+            timeout: None,
+            connect_timeout: None,
+            cache_ttl: None,
+            retry_policy: RetryPolicy::default(),
+            rate_limiter: None,
+            user_agent: None,
             mods_cache: Mutex::new(None),
             authors_cache: Mutex::new(None),
+            tags_cache: Mutex::new(None),
+            game_versions_cache: Mutex::new(None),
+            mods_detail_cache: Mutex::new(ModDetailCache::default()),
         }
     }
 
+    #[cfg(feature = "reqwest")]
+    pub(crate) fn set_base_url(&mut self, base_url: impl Into<String>) {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+    }
+
+    /// Whether results from `/mods`, `/mod/{id}`, `/authors`, `/tags` and `/gameversions` are cached in memory.
+    pub fn is_cache_enabled(&self) -> bool {
+        self.enable_cache
+    }
+
+    /// A snapshot of how this client is configured, e.g. for logging.
+    pub fn config(&self) -> ApiConfig {
+        ApiConfig {
+            base_url: self.base_url.clone(),
+            asset_host: self.asset_host.clone(),
+            enable_cache: self.enable_cache,
+            timeout: self.timeout,
+            connect_timeout: self.connect_timeout,
+            cache_ttl: self.cache_ttl,
+            retry_policy: self.retry_policy.clone(),
+            requests_per_second: self.rate_limiter.as_ref().map(|limiter| limiter.requests_per_second),
+            user_agent: self.user_agent.clone(),
+        }
+    }
+
+    /// Treat cached results older than `ttl` as missing, so they are fetched again on the next call.
+    ///
+    /// Without a TTL cached results live until cleared.
+    pub fn with_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.cache_ttl = Some(ttl);
+        self
+    }
+
+    /// Retry transient failures according to `policy`. By default requests aren't retried.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Space requests out so at most `requests_per_second` are sent, shared by all concurrent calls (retries included).
+    ///
+    /// Values that aren't positive and finite disable the limit. Rates below one request per day are raised to
+    /// that, so the wait between requests stays representable.
+    pub fn with_rate_limit(mut self, requests_per_second: f64) -> Self {
+        self.rate_limiter = (requests_per_second.is_finite() && requests_per_second > 0.0)
+            .then(|| RateLimiter::new(requests_per_second.max(RateLimiter::MIN_REQUESTS_PER_SECOND)));
+        self
+    }
+
+    /// Whether a cache entry fetched at `fetched` is still within the TTL.
+    fn is_fresh(&self, fetched: Instant) -> bool {
+        self.cache_ttl.is_none_or(|ttl| fetched.elapsed() < ttl)
+    }
+
+    /// Use `host` (e.g. `https://cdn.example.com`) instead of the mod DB's CDN when building asset URLs.
+    pub fn with_asset_host(mut self, host: impl Into<String>) -> Self {
+        self.asset_host = host.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// The host asset URLs are built against.
+    pub fn asset_host(&self) -> &str {
+        &self.asset_host
+    }
+
+    /// Resolve an asset path against the configured asset host, leaving absolute URLs untouched.
+    pub fn asset_url(&self, path: &str) -> String {
+        crate::models::resolve_url(&self.asset_host, path)
+    }
+
+    /// URL of a mod's logo on the configured asset host, see [`DetailedMod::logo_url`] for which fields are used.
+    pub fn logo_url(&self, mod_info: &DetailedMod) -> Option<String> {
+        mod_info.logo_url_on(&self.asset_host)
+    }
+
+    /// URL of a screenshot's full-size image.
+    pub fn screenshot_url(&self, screenshot: &DetailedModScreenshot) -> String {
+        screenshot.image_url_on(&self.asset_host)
+    }
+
+    /// URL of a screenshot's thumbnail, see [`DetailedModScreenshot::thumbnail_url`].
+    pub fn screenshot_thumbnail_url(&self, screenshot: &DetailedModScreenshot) -> String {
+        screenshot.thumbnail_url_on(&self.asset_host)
+    }
+
+    /// Send a GET request to `url`, retrying transient failures according to the retry policy.
+    ///
+    /// Gives up with the last error, or returns the last response, once the retries are used up.
+    async fn send_with_retry(&self, url: &str, headers: &[(&str, &str)]) -> Result<TransportResponse, ApiError> {
+        let result = self.retry(|| self.transport.get_with_headers(url, headers), |result| match result {
+            Ok(resp) => (resp.status >= 500 || resp.status == 429).then(|| resp.retry_after()),
+            Err(e) => e.is_transient().then_some(None),
+        }).await;
+        Ok(result?)
+    }
+
+    /// Run `send` until it succeeds or retries run out, waiting for the rate limiter before every attempt.
+    ///
+    /// `retry_wait` returns `None` if a result is final, or `Some` with the server's `Retry-After` delay if one was sent.
+    async fn retry<R, Fut: Future<Output = R>>(&self, mut send: impl FnMut() -> Fut, retry_wait: impl Fn(&R) -> Option<Option<Duration>>) -> R {
+        let mut attempt = 0;
+        loop {
+            if let Some(limiter) = &self.rate_limiter {
+                let wait = limiter.reserve();
+                if !wait.is_zero() {
+                    self.transport.sleep(wait).await;
+                }
+            }
+            let result = send().await;
+            let retry_after = match retry_wait(&result) {
+                Some(retry_after) if attempt < self.retry_policy.max_retries => retry_after,
+                _ => return result,
+            };
+
+            self.transport.sleep(retry_after.unwrap_or_else(|| self.retry_policy.delay(attempt))).await;
+            attempt += 1;
+        }
+    }
+
+    /// Send a GET request to `url` and deserialize the JSON response.
+    ///
+    /// Every request goes through here, so `endpoint` (the path without ids or query) labels it for metrics and errors.
+    /// A `statuscode` other than `"200"` in the body is returned as `ApiError::Status`, even if the rest didn't parse.
+    /// Missing resources, by HTTP status or `statuscode`, are returned as `ApiError::NotFound`.
+    async fn get_json<R: DeserializeOwned + ApiResponse>(&self, endpoint: &'static str, url: String) -> Result<R, ApiError> {
+        let (value, _) = self.get_json_if_modified(endpoint, url, &Validators::default()).await?
+            .ok_or_else(|| ApiError::HttpStatus { status: 304, endpoint: endpoint.to_string() })?;
+        Ok(value)
+    }
+
+    /// Like [`Self::get_json`], sending `validators` from a cached copy along.
+    ///
+    /// Returns `None` if the server answered `304 Not Modified`, otherwise the value and its new validators.
+    async fn get_json_if_modified<R: DeserializeOwned + ApiResponse>(&self, endpoint: &'static str, url: String, validators: &Validators) -> Result<Option<(R, Validators)>, ApiError> {
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
+        #[cfg(feature = "metrics")]
+        let mut http_status = None;
+
+        let status_error = |code: &str| match code {
+            "404" => ApiError::NotFound { endpoint: endpoint.to_string() },
+            _ => ApiError::Status { code: code.to_string(), endpoint: endpoint.to_string() },
+        };
+        let result = async {
+            let headers = validators.request_headers();
+            let response = self.send_with_retry(&url, &headers).await?;
+            #[cfg(feature = "metrics")]
+            {
+                http_status = Some(response.status);
+            }
+            if response.status == 304 && !headers.is_empty() {
+                return Ok(None);
+            }
+            let (status, body) = (response.status, &response.body);
+            match serde_json::from_str::<R>(body) {
+                Ok(value) if value.status_code() != "200" => Err(status_error(value.status_code())),
+                Ok(value) => Ok(Some((value, Validators::from_response(&response)))),
+                Err(e) => match serde_json::from_str::<StatusResponse>(body) {
+                    Ok(response) if response.status_code != "200" => Err(status_error(&response.status_code)),
+                    _ if status == 404 => Err(ApiError::NotFound { endpoint: endpoint.to_string() }),
+                    _ if !(200..300).contains(&status) => Err(ApiError::HttpStatus { status, endpoint: endpoint.to_string() }),
+                    _ => Err(ApiError::Decode { endpoint: endpoint.to_string(), body_snippet: body_snippet(body), source: e }),
+                },
+            }
+        }.await;
+
+        #[cfg(feature = "metrics")]
+        metrics_support::record_request(endpoint, http_status, start.elapsed(), result.is_ok());
+        result
+    }
+
+    /// Build the URL for `path` with `params` percent-encoded into the query string.
+    fn query_url<K: AsRef<str>, V: AsRef<str>>(&self, path: &str, params: &[(K, V)]) -> String {
+        let query = form_urlencoded::Serializer::new(String::new()).extend_pairs(params).finish();
+        format!("{}{}?{}", self.base_url, path, query)
+    }
+
     /// Get all mods from the API.
     ///
     /// Uses cache if enabled. Returns `SimpleMod` entries with limited information.
+    /// Like `/authors`, the `/mods` endpoint is not paginated, so this is the complete list.
     pub async fn get_mods(&self) -> Result<Vec<SimpleMod>, ApiError> {
+        if self.enable_cache
+            && let Some(cached) = self.mods_cache.lock().unwrap().as_ref().filter(|c| self.is_fresh(c.fetched))
+        {
+            #[cfg(feature = "metrics")]
+            metrics_support::record_cache_hit("mods");
+            return Ok(cached.mods.clone());
+        }
+        #[cfg(feature = "metrics")]
         if self.enable_cache {
-            if let Some(cached) = self.mods_cache.lock().unwrap().as_ref() {
-                return Ok(cached.clone());
-            }
+            metrics_support::record_cache_miss("mods");
         }
 
-        let resp = self.client.get(format!("{}/mods", Self::BASE_URL)).send().await?;
-        let mods_response: ModsResponse = resp.json().await?;
-        let mods = mods_response.mods;
-
-        if self.enable_cache {
-            *self.mods_cache.lock().unwrap() = Some(mods.clone());
+        if !self.enable_cache {
+            let mods_response: ModsResponse = self.get_json("/mods", format!("{}/mods", self.base_url)).await?;
+            return Ok(mods_response.mods);
         }
-        Ok(mods)
+        Ok(self.revalidate_mods().await?.0)
     }
 
     /// Refreshes the mods cache from the API.
-    pub async fn refresh_mods_cache(&self) -> Result<(), ApiError> {
-        let resp = self.client.get(format!("{}/mods", Self::BASE_URL)).send().await?;
-        let mods_response: ModsResponse = resp.json().await?;
-        *self.mods_cache.lock().unwrap() = Some(mods_response.mods);
-        Ok(())
+    ///
+    /// The request is conditional on the cached copy's `ETag` or `Last-Modified`, so an unchanged list isn't downloaded
+    /// again. Returns whether the list changed, or `true` if there was nothing cached to compare with.
+    pub async fn refresh_mods_cache(&self) -> Result<bool, ApiError> {
+        Ok(self.revalidate_mods().await?.1)
+    }
+
+    /// Fetch `/mods` into the cache if it changed since the cached copy, returning the mods and whether they changed.
+    async fn revalidate_mods(&self) -> Result<(Vec<SimpleMod>, bool), ApiError> {
+        let url = format!("{}/mods", self.base_url);
+        let validators = self.mods_cache.lock().unwrap().as_ref().map(|c| c.validators.clone()).unwrap_or_default();
+        let fetched = match self.get_json_if_modified::<ModsResponse>("/mods", url.clone(), &validators).await? {
+            Some(fetched) => fetched,
+            None => {
+                if let Some(cache) = self.mods_cache.lock().unwrap().as_mut() {
+                    cache.fetched = Instant::now();
+                    return Ok((cache.mods.clone(), false));
+                }
+                // The cache was cleared while the request was in flight
+                self.get_json_if_modified("/mods", url, &Validators::default()).await?
+                    .ok_or_else(|| ApiError::HttpStatus { status: 304, endpoint: "/mods".to_string() })?
+            }
+        };
+        let (mods_response, validators) = fetched;
+        *self.mods_cache.lock().unwrap() = Some(ModsCache::new(mods_response.mods.clone(), validators));
+        Ok((mods_response.mods, true))
     }
 
     /// Get detailed mod information for a specific mod ID.
@@ -62,12 +539,86 @@ impl VintageStoryModDbApi {
         self.get_mod_from_alias(mod_id.to_string()).await
     }
 
-    /// Get detailed mod information from a mod alias
+    /// Get detailed mod information from a mod alias (or a numeric mod id).
+    ///
+    /// The alias is trimmed and lowercased first. Aliases that can't be a single path segment, such as a pasted
+    /// URL, return `ApiError::Unexpected` without making a request.
+    ///
+    /// Uses cache if enabled, keyed by both mod id and alias.
     pub async fn get_mod_from_alias(&self, alias: impl AsRef<str>) -> Result<DetailedMod, ApiError> {
-        let url = format!("{}/mod/{}", Self::BASE_URL, alias.as_ref());
-        let resp = self.client.get(url).send().await?;
-        let mod_response: ModResponse = resp.json().await?;
-        Ok(mod_response.mod_info)
+        let alias = &normalize_alias(alias.as_ref())?;
+        if self.enable_cache
+            && let Some(cached) = self.mods_detail_cache.lock().unwrap().get(alias).filter(|(fetched, _)| self.is_fresh(*fetched)).map(|(_, m)| m)
+        {
+            #[cfg(feature = "metrics")]
+            metrics_support::record_cache_hit("mod");
+            return Ok(cached.clone());
+        }
+        #[cfg(feature = "metrics")]
+        if self.enable_cache {
+            metrics_support::record_cache_miss("mod");
+        }
+
+        let url = format!("{}/mod/{}", self.base_url, alias);
+        let mod_response: ModResponse = self.get_json("/mod", url).await?;
+        let mod_info = mod_response.mod_info;
+
+        if self.enable_cache {
+            self.mods_detail_cache.lock().unwrap().insert(mod_info.clone());
+        }
+        Ok(mod_info)
+    }
+
+    /// Get detailed mod information from a mod alias, resolving the alias through the mods cache first.
+    ///
+    /// If the mods cache is populated, the alias is mapped to its `mod_id` before fetching, and an alias that
+    /// isn't in the cache returns `ApiError::Unexpected` without making a request. With an empty cache this
+    /// behaves like [`Self::get_mod_from_alias`].
+    pub async fn get_mod_from_alias_cached(&self, alias: impl AsRef<str>) -> Result<DetailedMod, ApiError> {
+        let alias = &normalize_alias(alias.as_ref())?;
+        let resolved = self.mods_cache.lock().unwrap().as_ref().filter(|c| self.is_fresh(c.fetched)).map(|cache| {
+            cache.mods.iter()
+                .find(|m| m.url_alias.as_deref().is_some_and(|a| a.eq_ignore_ascii_case(alias)))
+                .map(|m| m.mod_id)
+        });
+
+        match resolved {
+            Some(Some(mod_id)) => self.get_mod(mod_id).await,
+            Some(None) => Err(ApiError::Unexpected(format!("No mod with alias {} in the mods cache", alias))),
+            None => self.get_mod_from_alias(alias).await,
+        }
+    }
+
+    /// Find the mod with `asset_id`, e.g. from a comment, or `None` if no mod has it.
+    ///
+    /// With the mods cache populated this is an index lookup, otherwise the mods list is fetched and searched.
+    pub async fn get_mod_by_asset_id(&self, asset_id: u32) -> Result<Option<SimpleMod>, ApiError> {
+        let fresh_cache = |cache: &Option<ModsCache>| cache.as_ref().filter(|c| self.is_fresh(c.fetched)).map(|c| c.by_asset_id(asset_id).cloned());
+        if let Some(found) = fresh_cache(&self.mods_cache.lock().unwrap()) {
+            return Ok(found);
+        }
+        let mods = self.get_mods().await?;
+        if let Some(found) = fresh_cache(&self.mods_cache.lock().unwrap()) {
+            return Ok(found);
+        }
+        Ok(mods.into_iter().find(|m| m.asset_id == asset_id))
+    }
+
+    /// Get detailed mod information, falling back to a stub built from the cached `SimpleMod`
+    /// if the request fails with a transient error.
+    ///
+    /// Stubs have `is_stub` set and lack releases, screenshots and links. Non-transient errors,
+    /// or a mod missing from the mods cache, still return the original error.
+    pub async fn get_mod_or_stub(&self, mod_id: u32) -> Result<DetailedMod, ApiError> {
+        match self.get_mod(mod_id).await {
+            Ok(detailed) => Ok(detailed),
+            Err(e) if e.is_transient() => {
+                let cached = self.mods_cache.lock().unwrap().as_ref()
+                    .and_then(|cache| cache.mods.iter().find(|m| m.mod_id == mod_id).cloned());
+                cached.map(DetailedMod::from).ok_or(e)
+            }
+            Err(e) => Err(e),
+        }
     }
 
     /// Convert a simple mod to detailed mod information (helper convenience method).
@@ -75,86 +626,470 @@ impl VintageStoryModDbApi {
         self.get_mod(simple.mod_id).await
     }
 
+    /// Get the details of each mod in `ids`, at most `concurrency` requests at a time.
+    ///
+    /// Each id gets its own result in the same order as `ids`, so one missing mod doesn't discard the rest.
+    /// Uses cache if enabled.
+    pub async fn get_mods_detailed(&self, ids: &[u32], concurrency: usize) -> Result<Vec<Result<DetailedMod, ApiError>>, ApiError> {
+        let mut results: Vec<_> = stream::iter(ids.iter().enumerate())
+            .map(|(i, &mod_id)| async move { (i, self.get_mod(mod_id).await) })
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await;
+        results.sort_by_key(|(i, _)| *i);
+        Ok(results.into_iter().map(|(_, result)| result).collect())
+    }
+
     /// Search for mods using the site. Set ascending to true to search in ascending order, and set sort_by to None to search in recently updated
     pub async fn search_mods(&self, query: impl AsRef<str>, ascending: bool, sort_by: impl Into<Option<SortBy>>) -> Result<Vec<SimpleMod>, ApiError> {
-        let sort_by = sort_by.into().unwrap_or_default();
+        self.search(query.as_ref(), ascending, sort_by.into().unwrap_or_default(), &SearchFilter::default()).await
+    }
 
-        let url = format!("{}/mods?text={}&sortby={}&sortdir={}&side=&userid=0&mv=", Self::BASE_URL, query.as_ref(), sort_by, if ascending { "a" } else { "d" });
-        let resp = self.client.get(url).send().await?;
+    /// Search for mods like [`Self::search_mods`], limited to mods with any of `tags` (tag ids from [`Self::get_tags`]).
+    ///
+    /// Filtering happens server-side, so results keep the site's relevance sorting. An empty `tags` means no tag filter.
+    pub async fn search_mods_filtered(&self, query: impl AsRef<str>, tags: &[u32], sort_by: impl Into<Option<SortBy>>, ascending: bool) -> Result<Vec<SimpleMod>, ApiError> {
+        let filter = SearchFilter { tag_ids: tags.to_vec(), ..Default::default() };
+        self.search(query.as_ref(), ascending, sort_by.into().unwrap_or_default(), &filter).await
+    }
 
-        let mods: ModsResponse = resp.json().await?;
+    /// Search for mods like [`Self::search_mods`], limited to mods with a release for `game_version`.
+    ///
+    /// The version is sent as the `mv` parameter, which takes the version's `tag_id` from
+    /// [`Self::get_game_versions`] (not its name), the same value the site's search form uses.
+    pub async fn search_mods_for_version(&self, query: impl AsRef<str>, game_version: &GameVersion, sort_by: impl Into<Option<SortBy>>, ascending: bool) -> Result<Vec<SimpleMod>, ApiError> {
+        let filter = SearchFilter { game_version: Some(game_version.tag_id), ..Default::default() };
+        self.search(query.as_ref(), ascending, sort_by.into().unwrap_or_default(), &filter).await
+    }
+
+    /// Get the mods tagged `tag_name`, matched against [`Self::get_tags`] ignoring case and surrounding whitespace.
+    ///
+    /// Returns `ApiError::NotFound` for `/tags` if no tag has that name.
+    pub async fn get_mods_by_tag_name(&self, tag_name: &str) -> Result<Vec<SimpleMod>, ApiError> {
+        let tag = self.get_tag_by_name(tag_name).await?
+            .ok_or_else(|| ApiError::NotFound { endpoint: "/tags".to_string() })?;
+        self.search_mods_filtered("", &[tag.tag_id], None, false).await
+    }
+
+    /// Get the mods published by the user with `user_id`, using the search's `userid` filter.
+    ///
+    /// This is the authoritative way to list an author's mods, as it doesn't depend on names.
+    pub async fn get_mods_by_user_id(&self, user_id: u32) -> Result<Vec<SimpleMod>, ApiError> {
+        self.search("", false, SortBy::default(), &SearchFilter { user_id, ..Default::default() }).await
+    }
+
+    /// Get the mods whose `author` matches `author`'s name, ignoring case and surrounding whitespace.
+    ///
+    /// Mods only carry the author's display name, so renamed authors or shared names can give wrong results,
+    /// prefer [`Self::get_mods_by_user_id`]. Authors without a name have no matches. Uses cache if enabled.
+    pub async fn get_mods_by_author(&self, author: &Author) -> Result<Vec<SimpleMod>, ApiError> {
+        let Some(name) = author.name.as_deref().map(str::trim).filter(|n| !n.is_empty()) else {
+            return Ok(vec![]);
+        };
+        let mods = self.get_mods().await?;
+        Ok(mods.into_iter().filter(|m| m.author.trim().eq_ignore_ascii_case(name)).collect())
+    }
+
+    /// Run a search on `/mods` with the site's query parameters.
+    async fn search(&self, query: &str, ascending: bool, sort_by: SortBy, filter: &SearchFilter) -> Result<Vec<SimpleMod>, ApiError> {
+        let mut params = vec![
+            ("text", query.to_string()),
+            ("sortby", sort_by.to_string()),
+            ("sortdir", if ascending { "a" } else { "d" }.to_string()),
+            ("side", String::new()),
+            ("userid", filter.user_id.to_string()),
+            ("mv", filter.game_version.map(|id| id.to_string()).unwrap_or_default()),
+        ];
+        params.extend(filter.tag_ids.iter().map(|id| ("tagids[]", id.to_string())));
+        let url = self.query_url("/mods", &params);
+        let mods: ModsResponse = self.get_json("/mods", url).await?;
         Ok(mods.mods)
     }
 
     /// Search mods by name
+    ///
+    /// Names match ignoring case, accents, whitespace and punctuation, so `"cafe decor"` finds `"Café Décor"`.
     pub async fn search_name(&self, query: impl AsRef<str>) -> Result<Vec<SimpleMod>, ApiError> {
         let mods = self.get_mods().await?;
 
-        Ok(mods.into_iter().filter(|m| m.name.replace(|c| char::is_ascii_whitespace(&c) || char::is_ascii_punctuation(&c), "").eq_ignore_ascii_case(&query.as_ref().replace(|c| char::is_ascii_whitespace(&c) || char::is_ascii_punctuation(&c), ""))).collect())
+        let query = normalize(query.as_ref());
+
+        Ok(mods.into_iter().filter(|m| normalize(&m.name) == query).collect())
+    }
+
+    /// Search mods by name tolerating typos and partial names, best matches first with their score.
+    ///
+    /// Names are compared after the same normalization as [`Self::search_name`]. The score is 1.0 for an exact match,
+    /// otherwise the better of the edit-distance similarity and a bonus for containing the query.
+    /// Mods scoring below 0.5 are left out, and at most `limit` are returned. Uses cache if enabled.
+    pub async fn search_name_fuzzy(&self, query: impl AsRef<str>, limit: usize) -> Result<Vec<(SimpleMod, f64)>, ApiError> {
+        let query = normalize(query.as_ref());
+        if query.is_empty() {
+            return Ok(vec![]);
+        }
+        let mut scored: Vec<_> = self.get_mods().await?.into_iter()
+            .map(|m| {
+                let score = fuzzy_score(&query, &normalize(&m.name));
+                (m, score)
+            })
+            .filter(|(_, score)| *score >= 0.5)
+            .collect();
+        scored.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+        scored.truncate(limit);
+        Ok(scored)
     }
 
     /// Search mods by mod id
     pub async fn search_mod_id(&self, query: impl AsRef<str>) -> Result<Vec<SimpleMod>, ApiError> {
         let mods = self.get_mods().await?;
 
-        Ok(mods.into_iter().filter(|m| m.mod_id_strs.contains(&query.as_ref().replace(|c| char::is_ascii_whitespace(&c) || char::is_ascii_punctuation(&c), "").to_ascii_lowercase())).collect())
+        let query = normalize(query.as_ref());
+
+        Ok(mods.into_iter().filter(|m| m.mod_id_strs.contains(&query)).collect())
     }
 
+    /// Search mods whose name or summary contains the query, ignoring case, whitespace and punctuation.
+    ///
+    /// Uses cache if enabled. Name matches are returned before summary-only matches.
+    pub async fn search_text_local(&self, query: impl AsRef<str>) -> Result<Vec<SimpleMod>, ApiError> {
+        let query = normalize(query.as_ref());
+        if query.is_empty() {
+            return Ok(vec![]);
+        }
+        let mods = self.get_mods().await?;
 
-    /// Get all tags (always live from API, no caching).
-    pub async fn get_tags(&self) -> Result<Vec<Tag>, ApiError> {
-        let url = format!("{}/tags", Self::BASE_URL);
-        let resp = self.client.get(url).send().await?;
-        let tags: TagsResponse = resp.json().await?;
-        Ok(tags.tags)
+        let (name_matches, rest): (Vec<_>, Vec<_>) = mods.into_iter().partition(|m| normalize(&m.name).contains(&query));
+        let summary_matches = rest.into_iter().filter(|m| m.summary.as_deref().is_some_and(|s| normalize(s).contains(&query)));
+        Ok(name_matches.into_iter().chain(summary_matches).collect())
     }
 
-    /// Get all authors.
+    /// Get every distinct `side` value in use across the mods list, sorted.
     ///
-    /// Uses cache if enabled.
-    pub async fn get_authors(&self) -> Result<Vec<Author>, ApiError> {
-        if self.enable_cache {
-            if let Some(cached) = self.authors_cache.lock().unwrap().as_ref() {
-                return Ok(cached.clone());
+    /// Mods with a missing or blank `side` are skipped. Uses cache if enabled.
+    pub async fn distinct_sides(&self) -> Result<Vec<String>, ApiError> {
+        let mods = self.get_mods().await?;
+        Ok(mods.into_iter().map(|m| m.side).filter(|v| !v.trim().is_empty()).collect::<BTreeSet<_>>().into_iter().collect())
+    }
+
+    /// Get every distinct `mod_type` value in use across the mods list, sorted.
+    ///
+    /// Mods with a missing or blank `mod_type` are skipped. Uses cache if enabled.
+    pub async fn distinct_mod_types(&self) -> Result<Vec<String>, ApiError> {
+        let mods = self.get_mods().await?;
+        Ok(mods.into_iter().map(|m| m.mod_type).filter(|v| !v.trim().is_empty()).collect::<BTreeSet<_>>().into_iter().collect())
+    }
+
+    /// Find mod id strings (`mod_id_strs`) claimed by more than one mod.
+    ///
+    /// Mods sharing an id string will conflict when installed together. The game treats mod ids
+    /// case-insensitively, so ids are compared and keyed in lowercase, and a mod listing the same id
+    /// twice is not reported against itself. Uses cache if enabled.
+    pub async fn find_modid_collisions(&self) -> Result<HashMap<String, Vec<SimpleMod>>, ApiError> {
+        let mods = self.get_mods().await?;
+        let mut owners: HashMap<String, Vec<SimpleMod>> = HashMap::new();
+        for m in mods {
+            for id in m.mod_id_strs.iter().map(|id| id.trim().to_lowercase()).collect::<BTreeSet<_>>() {
+                owners.entry(id).or_default().push(m.clone());
             }
         }
+        owners.retain(|_, mods| mods.len() > 1);
+        Ok(owners)
+    }
 
-        let url = format!("{}/authors", Self::BASE_URL);
-        let resp = self.client.get(url).send().await?;
-        let authors_response: AuthorsResponse = resp.json().await?;
-        let authors = authors_response.authors;
+    /// Count how many mods use each tag, after normalizing tag names so variants like `"QoL"` and `"qol"` are counted together.
+    ///
+    /// Uses cache if enabled. See [`Self::normalized_tag_variants`] to map the keys back to display names.
+    pub async fn normalized_tag_counts(&self) -> Result<HashMap<String, usize>, ApiError> {
+        let mods = self.get_mods().await?;
+        let mut counts = HashMap::new();
+        for tag in mods.iter().flat_map(|m| &m.tags) {
+            *counts.entry(normalize(tag)).or_default() += 1;
+        }
+        Ok(counts)
+    }
 
-        if self.enable_cache {
-            *self.authors_cache.lock().unwrap() = Some(authors.clone());
+    /// Map each normalized tag name to the display variants used for it across the mods list.
+    ///
+    /// Uses cache if enabled.
+    pub async fn normalized_tag_variants(&self) -> Result<HashMap<String, BTreeSet<String>>, ApiError> {
+        let mods = self.get_mods().await?;
+        let mut variants: HashMap<String, BTreeSet<String>> = HashMap::new();
+        for tag in mods.iter().flat_map(|m| &m.tags) {
+            variants.entry(normalize(tag)).or_default().insert(tag.trim().to_string());
+        }
+        Ok(variants)
+    }
+
+    /// Get mods tagged with any of `tag_ids`, each mod listed once in catalog order.
+    ///
+    /// The mods list only carries tag names, so the ids are resolved through `/tags` and compared
+    /// normalized. Unknown ids are ignored. Uses cache if enabled.
+    pub async fn get_mods_for_tags_union(&self, tag_ids: &[u32]) -> Result<Vec<SimpleMod>, ApiError> {
+        let names: BTreeSet<String> = self.get_tags().await?
+            .into_iter()
+            .filter(|t| tag_ids.contains(&t.tag_id))
+            .map(|t| normalize(&t.name))
+            .collect();
+        if names.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mods = self.get_mods().await?;
+        let mut seen = BTreeSet::new();
+        Ok(mods.into_iter()
+            .filter(|m| m.tags.iter().any(|t| names.contains(&normalize(t))))
+            .filter(|m| seen.insert(m.mod_id))
+            .collect())
+    }
+
+    /// The subset of `mod_ids` whose mods carry `tag`, compared normalized, in the order given.
+    ///
+    /// Uses the mods list (cached if enabled) instead of fetching details. Ids missing from it are left out.
+    pub async fn filter_by_tag(&self, mod_ids: &[u32], tag: &str) -> Result<Vec<u32>, ApiError> {
+        let tag = normalize(tag);
+        let mods = self.get_mods().await?;
+        let tagged: BTreeSet<u32> = mods.iter()
+            .filter(|m| m.tags.iter().any(|t| normalize(t) == tag))
+            .map(|m| m.mod_id)
+            .collect();
+        Ok(mod_ids.iter().copied().filter(|id| tagged.contains(id)).collect())
+    }
+
+    /// Get mods that have a `wiki_url` set.
+    ///
+    /// `SimpleMod` has no links, so this fetches details for every mod, at most `concurrency` requests at a time.
+    /// Mods that 404 while fetching details are skipped.
+    pub async fn get_documented_mods(&self, concurrency: usize) -> Result<Vec<DetailedMod>, ApiError> {
+        let mods = self.get_mods().await?;
+        let detailed = self.fetch_details(&mods, concurrency).await?;
+        Ok(detailed.into_iter().filter(|m| m.wiki_url.as_deref().is_some_and(|url| !url.trim().is_empty())).collect())
+    }
+
+    /// Get mods with at least one screenshot.
+    ///
+    /// `SimpleMod` has no screenshots, so this fetches details for every mod, at most `concurrency` requests at a time.
+    /// Mods that 404 while fetching details are skipped.
+    pub async fn get_mods_with_screenshots(&self, concurrency: usize) -> Result<Vec<DetailedMod>, ApiError> {
+        let mods = self.get_mods().await?;
+        let detailed = self.fetch_details(&mods, concurrency).await?;
+        Ok(detailed.into_iter().filter(|m| !m.screenshots.is_empty()).collect())
+    }
+
+    /// Get mods without any release for `game_version` (e.g. `"v1.20.3"`), the ones that need updating.
+    ///
+    /// Fetches details for every mod, at most `concurrency` requests at a time. Mods that 404 while fetching details are skipped.
+    pub async fn get_outdated_mods(&self, game_version: impl AsRef<str>, concurrency: usize) -> Result<Vec<SimpleMod>, ApiError> {
+        let mods = self.get_mods().await?;
+        let outdated: BTreeSet<u32> = self.fetch_details(&mods, concurrency).await?
+            .into_iter()
+            .filter(|d| !d.supports_version(game_version.as_ref()))
+            .map(|d| d.mod_id)
+            .collect();
+        Ok(mods.into_iter().filter(|m| outdated.contains(&m.mod_id)).collect())
+    }
+
+    /// Fraction (0.0 to 1.0) of mods with a release for `game_version`, e.g. `0.72` when 72% support it.
+    ///
+    /// Fetches details for every mod, at most `concurrency` requests at a time. Mods that 404 while fetching details
+    /// are left out of the total. Returns 0.0 for an empty catalog.
+    pub async fn version_adoption(&self, game_version: impl AsRef<str>, concurrency: usize) -> Result<f64, ApiError> {
+        let mods = self.get_mods().await?;
+        let detailed = self.fetch_details(&mods, concurrency).await?;
+        if detailed.is_empty() {
+            return Ok(0.0);
+        }
+        let supported = detailed.iter().filter(|d| d.supports_version(game_version.as_ref())).count();
+        Ok(supported as f64 / detailed.len() as f64)
+    }
+
+    /// Count how many mods have a release for each game version, keyed like `"v1.20.3"`.
+    ///
+    /// Fetches details for every mod, at most `concurrency` requests at a time. Each mod counts once per version,
+    /// mods that 404 while fetching details are skipped.
+    pub async fn mods_per_game_version(&self, concurrency: usize) -> Result<BTreeMap<String, usize>, ApiError> {
+        let mods = self.get_mods().await?;
+        let detailed = self.fetch_details(&mods, concurrency).await?;
+        let mut counts = BTreeMap::new();
+        for version in detailed.iter().flat_map(|d| d.supported_versions()) {
+            *counts.entry(version.to_string()).or_default() += 1;
         }
-        Ok(authors)
+        Ok(counts)
+    }
+
+    /// Fetch details for each of `mods`, at most `concurrency` requests at a time.
+    ///
+    /// Results are in the same order as `mods`. Mods that are gone (`ApiError::NotFound`), e.g. removed since the
+    /// mods list was fetched, are skipped like in [`Self::get_mods_detailed`]. Fails with the first other error.
+    async fn fetch_details(&self, mods: &[SimpleMod], concurrency: usize) -> Result<Vec<DetailedMod>, ApiError> {
+        stream::iter(mods)
+            .map(|m| self.get_mod(m.mod_id))
+            .buffered(concurrency.max(1))
+            .filter(|result| std::future::ready(!matches!(result, Err(ApiError::NotFound { .. }))))
+            .try_collect()
+            .await
+    }
+
+    /// Download, follow and comment totals across every mod. Uses cache if enabled.
+    pub async fn catalog_stats(&self) -> Result<CatalogStats, ApiError> {
+        Ok(CatalogStats::from_mods(&self.get_mods().await?))
+    }
+
+    /// A hash of every mod's `(mod_id, last_released, downloads)`, for cheaply detecting catalog changes between polls.
+    ///
+    /// Uses cache if enabled. The hash (64-bit FNV-1a) is stable across runs, platforms and crate builds,
+    /// so it can be persisted and compared later.
+    pub async fn catalog_fingerprint(&self) -> Result<u64, ApiError> {
+        let mut mods = self.get_mods().await?;
+        mods.sort_by_key(|m| m.mod_id);
+
+        let mut hash: u64 = 0xcbf29ce484222325;
+        let mut write = |bytes: &[u8]| {
+            for byte in bytes {
+                hash ^= *byte as u64;
+                hash = hash.wrapping_mul(0x100000001b3);
+            }
+        };
+        for m in &mods {
+            write(&m.mod_id.to_le_bytes());
+            write(m.last_released.as_bytes());
+            write(&[0xff]);
+            write(&m.downloads.to_le_bytes());
+        }
+        Ok(hash)
+    }
+
+    /// Get all tags.
+    ///
+    /// Uses cache if enabled.
+    pub async fn get_tags(&self) -> Result<Vec<Tag>, ApiError> {
+        self.get_cached_list(&self.tags_cache, "/tags", |r: TagsResponse| r.tags).await
+    }
+
+    /// Refreshes the tags cache from the API, returning whether the list changed, see [`Self::refresh_mods_cache`].
+    pub async fn refresh_tags_cache(&self) -> Result<bool, ApiError> {
+        Ok(self.revalidate_list(&self.tags_cache, "/tags", |r: TagsResponse| r.tags).await?.1)
+    }
+
+    /// Get the tag with `tag_id`, or `None` if there is none.
+    pub async fn get_tag_by_id(&self, tag_id: u32) -> Result<Option<Tag>, ApiError> {
+        Ok(self.get_tags().await?.into_iter().find(|t| t.tag_id == tag_id))
+    }
+
+    /// Get the tag named `name`, ignoring case and surrounding whitespace, or `None` if there is none.
+    ///
+    /// Mods list their tags by name, so this resolves them to ids and colors.
+    pub async fn get_tag_by_name(&self, name: &str) -> Result<Option<Tag>, ApiError> {
+        let name = name.trim().to_lowercase();
+        Ok(self.get_tags().await?.into_iter().find(|t| t.name.trim().to_lowercase() == name))
+    }
+
+    /// Get all authors.
+    ///
+    /// Uses cache if enabled. The `/authors` endpoint is not paginated and returns every author in a single response,
+    /// so this is always the complete list.
+    pub async fn get_authors(&self) -> Result<Vec<Author>, ApiError> {
+        self.get_cached_list(&self.authors_cache, "/authors", |r: AuthorsResponse| r.authors).await
+    }
+
+    /// Get the author with `user_id`, e.g. to name a comment's poster, or `None` if they aren't in the authors list.
+    ///
+    /// Uses cache if enabled.
+    pub async fn get_author_by_id(&self, user_id: u32) -> Result<Option<Author>, ApiError> {
+        Ok(self.get_authors().await?.into_iter().find(|a| a.userid == user_id))
     }
 
     /// Refreshes the authors cache from the API.
-    pub async fn refresh_authors_cache(&self) -> Result<(), ApiError> {
-        let url = format!("{}/authors", Self::BASE_URL);
-        let resp = self.client.get(url).send().await?;
-        let authors_response: AuthorsResponse = resp.json().await?;
-        *self.authors_cache.lock().unwrap() = Some(authors_response.authors);
-        Ok(())
+    ///
+    /// Like [`Self::refresh_mods_cache`], the request is conditional and the result is whether the list changed.
+    pub async fn refresh_authors_cache(&self) -> Result<bool, ApiError> {
+        Ok(self.revalidate_list(&self.authors_cache, "/authors", |r: AuthorsResponse| r.authors).await?.1)
     }
 
-    /// Get all game versions (always live from API, no caching).
+    /// Get all game versions.
+    ///
+    /// Uses cache if enabled.
     pub async fn get_game_versions(&self) -> Result<Vec<GameVersion>, ApiError> {
-        let url = format!("{}/gameversions", Self::BASE_URL);
-        let resp = self.client.get(url).send().await?;
-        let versions: GameVersionsResponse = resp.json().await?;
-        Ok(versions.game_versions)
+        self.get_cached_list(&self.game_versions_cache, "/gameversions", |r: GameVersionsResponse| r.game_versions).await
+    }
+
+    /// Refreshes the game versions cache from the API, returning whether the list changed, see [`Self::refresh_mods_cache`].
+    pub async fn refresh_game_versions_cache(&self) -> Result<bool, ApiError> {
+        Ok(self.revalidate_list(&self.game_versions_cache, "/gameversions", |r: GameVersionsResponse| r.game_versions).await?.1)
+    }
+
+    /// Get the list at `endpoint`, served from `cache` while it's fresh if caching is enabled.
+    async fn get_cached_list<R, I>(&self, cache: &Mutex<Option<ListCache<I>>>, endpoint: &'static str, items: fn(R) -> Vec<I>) -> Result<Vec<I>, ApiError>
+    where
+        R: DeserializeOwned + ApiResponse,
+        I: Clone,
+    {
+        #[cfg(feature = "metrics")]
+        let name = endpoint.trim_start_matches('/');
+        if self.enable_cache
+            && let Some(cached) = cache.lock().unwrap().as_ref().filter(|c| self.is_fresh(c.fetched))
+        {
+            #[cfg(feature = "metrics")]
+            metrics_support::record_cache_hit(name);
+            return Ok(cached.items.clone());
+        }
+        #[cfg(feature = "metrics")]
+        if self.enable_cache {
+            metrics_support::record_cache_miss(name);
+        }
+
+        if !self.enable_cache {
+            let response: R = self.get_json(endpoint, format!("{}{}", self.base_url, endpoint)).await?;
+            return Ok(items(response));
+        }
+        Ok(self.revalidate_list(cache, endpoint, items).await?.0)
+    }
+
+    /// Fetch the list at `endpoint` into `cache` if it changed since the cached copy, see [`Self::revalidate_mods`].
+    async fn revalidate_list<R, I>(&self, cache: &Mutex<Option<ListCache<I>>>, endpoint: &'static str, items: fn(R) -> Vec<I>) -> Result<(Vec<I>, bool), ApiError>
+    where
+        R: DeserializeOwned + ApiResponse,
+        I: Clone,
+    {
+        let url = format!("{}{}", self.base_url, endpoint);
+        let validators = cache.lock().unwrap().as_ref().map(|c| c.validators.clone()).unwrap_or_default();
+        let fetched = match self.get_json_if_modified::<R>(endpoint, url.clone(), &validators).await? {
+            Some(fetched) => fetched,
+            None => {
+                if let Some(cache) = cache.lock().unwrap().as_mut() {
+                    cache.fetched = Instant::now();
+                    return Ok((cache.items.clone(), false));
+                }
+                self.get_json_if_modified(endpoint, url, &Validators::default()).await?
+                    .ok_or_else(|| ApiError::HttpStatus { status: 304, endpoint: endpoint.to_string() })?
+            }
+        };
+        let (response, validators) = fetched;
+        let fetched_items = items(response);
+        *cache.lock().unwrap() = Some(ListCache { items: fetched_items.clone(), fetched: Instant::now(), validators });
+        Ok((fetched_items, true))
     }
 
     /// Get all comments for a specific asset ID.
+    ///
+    /// Comments are read-only, the API has no endpoint for posting them.
     pub async fn get_comments(&self, asset_id: u32) -> Result<Vec<Comment>, ApiError> {
-        let url = format!("{}/comments/{}", Self::BASE_URL, asset_id);
-        let resp = self.client.get(url).send().await?;
-        let comments: CommentsResponse = resp.json().await?;
+        let url = format!("{}/comments/{}", self.base_url, asset_id);
+        let comments: CommentsResponse = self.get_json("/comments", url).await?;
         Ok(comments.comments)
     }
 
+    /// Get the `n` newest comments for an asset, newest first.
+    ///
+    /// The `/comments/{assetid}` endpoint has no pagination, so every comment is still downloaded and sorted locally
+    /// by the parsed `created` timestamp. Comments with the same date, or one that doesn't parse, are ordered by
+    /// `comment_id`, newest first, and comments with unparseable dates go last.
+    pub async fn get_latest_comments(&self, asset_id: u32, n: usize) -> Result<Vec<Comment>, ApiError> {
+        let mut comments = self.get_comments(asset_id).await?;
+        comments.sort_by_key(|c| std::cmp::Reverse(c.created_key()));
+        comments.truncate(n);
+        Ok(comments)
+    }
+
     /// Clear cached mods.
     pub fn clear_mods_cache(&self) {
         self.mods_cache.lock().unwrap().take();
@@ -165,10 +1100,61 @@ impl VintageStoryModDbApi {
         self.authors_cache.lock().unwrap().take();
     }
 
+    /// Clear cached tags.
+    pub fn clear_tags_cache(&self) {
+        self.tags_cache.lock().unwrap().take();
+    }
+
+    /// Clear cached game versions.
+    pub fn clear_game_versions_cache(&self) {
+        self.game_versions_cache.lock().unwrap().take();
+    }
+
+    /// Clear cached detailed mods.
+    pub fn clear_mod_detail_cache(&self) {
+        *self.mods_detail_cache.lock().unwrap() = ModDetailCache::default();
+    }
+
     /// Clear all cached data.
     pub fn clear_all_caches(&self) {
         self.clear_mods_cache();
         self.clear_authors_cache();
+        self.clear_tags_cache();
+        self.clear_game_versions_cache();
+        self.clear_mod_detail_cache();
+    }
+
+    /// Save the mods, authors, tags and game versions to a JSON [`CatalogSnapshot`] at `path`.
+    ///
+    /// Uses cache if enabled, otherwise the data is fetched.
+    pub async fn export_snapshot(&self, path: impl AsRef<Path>) -> Result<(), ApiError> {
+        let snapshot = CatalogSnapshot {
+            version: CatalogSnapshot::VERSION,
+            mods: self.get_mods().await?,
+            authors: self.get_authors().await?,
+            tags: self.get_tags().await?,
+            game_versions: self.get_game_versions().await?,
+        };
+        tokio::fs::write(path, serde_json::to_vec_pretty(&snapshot)?).await?;
+        Ok(())
+    }
+
+    /// Load a snapshot written by [`Self::export_snapshot`], filling the mods, authors, tags and game versions caches from it.
+    ///
+    /// The cached data counts as fetched now for the cache TTL, and is only served if caching is enabled.
+    /// Returns `ApiError::Unexpected` for snapshots of another format version.
+    pub async fn import_snapshot(&self, path: impl AsRef<Path>) -> Result<CatalogSnapshot, ApiError> {
+        let snapshot: CatalogSnapshot = serde_json::from_slice(&tokio::fs::read(path).await?)?;
+        if snapshot.version != CatalogSnapshot::VERSION {
+            return Err(ApiError::Unexpected(format!(
+                "Unsupported snapshot version {}, expected {}", snapshot.version, CatalogSnapshot::VERSION
+            )));
+        }
+        *self.mods_cache.lock().unwrap() = Some(ModsCache::new(snapshot.mods.clone(), Validators::default()));
+        *self.authors_cache.lock().unwrap() = Some(ListCache { items: snapshot.authors.clone(), fetched: Instant::now(), validators: Validators::default() });
+        *self.tags_cache.lock().unwrap() = Some(ListCache { items: snapshot.tags.clone(), fetched: Instant::now(), validators: Validators::default() });
+        *self.game_versions_cache.lock().unwrap() = Some(ListCache { items: snapshot.game_versions.clone(), fetched: Instant::now(), validators: Validators::default() });
+        Ok(snapshot)
     }
 
     pub async fn get_most_recent_release(&self, mod_id: u32) -> Result<DetailedModRelease, ApiError> {
@@ -183,10 +1169,25 @@ impl VintageStoryModDbApi {
         self.get_most_recent_release_from_alias_with_version(mod_id.to_string(), version).await
     }
 
+    /// Get every release of a mod tagged with game `version` (e.g. `"v1.20.3"`), newest first.
+    ///
+    /// Returns an empty list if no release supports the version.
+    pub async fn get_releases_for_version(&self, mod_id: u32, version: impl AsRef<str>) -> Result<Vec<DetailedModRelease>, ApiError> {
+        let mod_info = self.get_mod(mod_id).await?;
+        Ok(mod_info.releases_for(version.as_ref()).into_iter().cloned().collect())
+    }
+
+    /// Whether any release of a mod is tagged with game `version`, see [`DetailedMod::supports_version`].
+    pub async fn mod_supports_version(&self, mod_id: u32, version: impl AsRef<str>) -> Result<bool, ApiError> {
+        Ok(self.get_mod(mod_id).await?.supports_version(version.as_ref()))
+    }
+
+    /// The newest game version, including prereleases.
+    ///
+    /// Returns `ApiError::Empty` if the API lists no game versions.
     pub async fn get_most_recent_game_version(&self) -> Result<GameVersion, ApiError> {
         let versions = self.get_game_versions().await?;
-
-        Ok(versions.last().unwrap().clone())
+        versions.last().cloned().ok_or(ApiError::Empty("game versions"))
     }
     
     pub async fn get_mod_release_with_version(&self, mod_id: u32, version: impl AsRef<str>) -> Result<DetailedModRelease, ApiError> {
@@ -195,7 +1196,7 @@ impl VintageStoryModDbApi {
     
     pub async fn get_mod_release_with_version_from_alias(&self, alias: impl AsRef<str>, version: impl AsRef<str>) -> Result<DetailedModRelease, ApiError> {
         let mod_info = self.get_mod_from_alias(&alias).await?;
-        let mut releases = mod_info.releases;
+        let mut releases = mod_info.releases_sorted();
         releases.retain(|release_info| release_info.mod_version == version.as_ref());
         if releases.is_empty() {
             return Err(ApiError::Unexpected(format!(
@@ -207,38 +1208,387 @@ impl VintageStoryModDbApi {
         if releases.len() > 1 {
             println!("Found multiple releases {} for {}", releases.len(), alias.as_ref());
         }
-        Ok(releases.first().unwrap().clone())
+        Ok(releases[0].clone())
     }
 
+    /// The newest game version that isn't a prerelease, release candidate or dev build.
+    ///
+    /// Versions are compared by number, not by their order in the API's list.
+    /// Returns `ApiError::Empty` if the API lists no stable game versions.
     pub async fn get_most_recent_stable_game_version(&self) -> Result<GameVersion, ApiError> {
         let versions = self.get_game_versions().await?;
-        let mut dif = 1;
-        while versions[versions.len() - dif].name.contains("pre") || versions[versions.len() - dif].name.contains("rc") || versions[versions.len() - dif].name.contains("dev") {
-            dif += 1;
-        }
-
-        Ok(versions[versions.len() - dif].clone())
+        versions.into_iter()
+            .filter_map(|v| v.parsed().filter(|parsed| parsed.is_stable()).map(|parsed| (parsed, v)))
+            .max_by(|a, b| a.0.cmp(&b.0))
+            .map(|(_, v)| v)
+            .ok_or(ApiError::Empty("stable game versions"))
     }
 
+    /// The newest release tagged with game `version`, or the newest release overall if none is.
+    ///
+    /// Releases are ordered by their `created` date, not by their position in the API's list.
+    /// Returns `ApiError::Empty` if the mod has no releases.
     pub async fn get_most_recent_release_from_alias_with_version(&self, alias: impl AsRef<str>, version: impl AsRef<str>) -> Result<DetailedModRelease, ApiError> {
         let mod_info = self.get_mod_from_alias(alias).await?;
-        let mut iter = mod_info.releases.iter();
-        let mut current = iter.next().unwrap();
-        if current.tags.contains(&version.as_ref().to_string()) {
-            return Ok(current.clone());
+        let releases = mod_info.releases_sorted();
+        releases.iter()
+            .find(|release| release.game_version_tags().contains(&version.as_ref()))
+            .or(releases.first())
+            .map(|release| (*release).clone())
+            .ok_or(ApiError::Empty("releases"))
+    }
+
+    /// The newest release of a mod tagged for the latest stable game version, see
+    /// [`Self::get_most_recent_stable_game_version`].
+    ///
+    /// Returns `None` if no release supports that version, rather than falling back to another release.
+    pub async fn get_recommended_release(&self, mod_id: u32) -> Result<Option<DetailedModRelease>, ApiError> {
+        let version = self.get_most_recent_stable_game_version().await?;
+        let mod_info = self.get_mod(mod_id).await?;
+        Ok(mod_info.best_release_for(&version.name).cloned())
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl VintageStoryModDbApi {
+    /// Fetch a mod, pick its newest release for `game_version`, download it, and verify the download.
+    ///
+    /// The API does not publish checksums, so verification checks that the server reported success,
+    /// that the body is non-empty and matches `Content-Length` when one was sent, and that `.zip` releases
+    /// start with a zip signature. Any failed check is returned as `ApiError::Unexpected`.
+    pub async fn fetch_verified_release(&self, mod_id: u32, game_version: impl AsRef<str>) -> Result<(DetailedModRelease, Vec<u8>), ApiError> {
+        let mod_info = self.get_mod(mod_id).await?;
+        let release = mod_info.best_release_for(game_version.as_ref()).cloned().ok_or_else(|| ApiError::Unexpected(format!(
+            "No release of {} for game version {}",
+            mod_id,
+            game_version.as_ref()
+        )))?;
+
+        let resp = self.send_request(|client| client.get(release.download_link())).await?.error_for_status()?;
+        let expected_len = resp.content_length();
+        let bytes = resp.bytes().await?.to_vec();
+
+        if bytes.is_empty() {
+            return Err(ApiError::Unexpected(format!("Downloaded release {} is empty", release.release_id)));
         }
+        if let Some(expected_len) = expected_len
+            && expected_len != bytes.len() as u64
+        {
+            return Err(ApiError::Unexpected(format!(
+                "Downloaded release {} is {} bytes, expected {}",
+                release.release_id,
+                bytes.len(),
+                expected_len
+            )));
+        }
+        if release.get_filename().to_ascii_lowercase().ends_with(".zip") && !bytes.starts_with(b"PK\x03\x04") {
+            return Err(ApiError::Unexpected(format!("Downloaded release {} is not a valid zip archive", release.release_id)));
+        }
+
+        Ok((release, bytes))
+    }
+
+    /// Download a release's main file to `path`, overwriting any existing file.
+    ///
+    /// Returns the number of bytes written.
+    pub async fn download_release_to(&self, release: &DetailedModRelease, path: impl AsRef<Path>) -> Result<u64, ApiError> {
+        let resp = self.send_request(|client| client.get(release.download_link())).await?.error_for_status()?;
+        let mut file = File::create(path).await?;
+        Self::write_body(resp, &mut file).await
+    }
+
+    /// Download a release's main file into `dest_dir`, named by [`DetailedModRelease::get_filename`].
+    ///
+    /// Only the last path segment of the name is used, so a filename can't escape `dest_dir`.
+    /// Returns the path of the downloaded file.
+    pub async fn download_release(&self, release: &DetailedModRelease, dest_dir: impl AsRef<Path>) -> Result<PathBuf, ApiError> {
+        let filename = release.get_filename();
+        let filename = filename.rsplit(['/', '\\']).find(|s| !s.is_empty() && *s != "..").unwrap_or("release.zip");
+        let path = dest_dir.as_ref().join(filename);
+        self.download_release_to(release, &path).await?;
+        Ok(path)
+    }
+
+    /// Continue an interrupted download of a release's main file at `path`.
+    ///
+    /// Sends a `Range` request starting at the existing file's length and appends the rest. If the file
+    /// doesn't exist yet, or the server ignores ranges, the download restarts from scratch.
+    /// Returns the final size of the file.
+    pub async fn resume_release_download_to(&self, release: &DetailedModRelease, path: impl AsRef<Path>) -> Result<u64, ApiError> {
+        let path = path.as_ref();
+        let offset = match tokio::fs::metadata(path).await {
+            Ok(metadata) => metadata.len(),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => 0,
+            Err(e) => return Err(e.into()),
+        };
+        if offset == 0 {
+            return self.download_release_to(release, path).await;
+        }
+
+        let resp = self.send_request(|client| client.get(release.download_link()).header(RANGE, format!("bytes={}-", offset))).await?;
 
-        while let Some(release) = iter.next() {
-            if release.tags.contains(&version.as_ref().to_string()) {
-                current = release;
-                break;
+        match resp.status() {
+            StatusCode::PARTIAL_CONTENT => {
+                let mut file = OpenOptions::new().append(true).open(path).await?;
+                Ok(offset + Self::write_body(resp, &mut file).await?)
             }
+            StatusCode::RANGE_NOT_SATISFIABLE => {
+                // Either the file is already complete, or it's bigger than the remote file and has to be redone
+                let total = resp.headers().get(CONTENT_RANGE)
+                    .and_then(|v| v.to_str().ok())
+                    .and_then(|v| v.rsplit('/').next())
+                    .and_then(|v| v.parse::<u64>().ok());
+                if total == Some(offset) {
+                    Ok(offset)
+                } else {
+                    self.download_release_to(release, path).await
+                }
+            }
+            status if status.is_success() => {
+                let mut file = File::create(path).await?;
+                Self::write_body(resp, &mut file).await
+            }
+            _ => Err(resp.error_for_status().unwrap_err().into()),
+        }
+    }
+
+    /// Send a request built by `request` directly through the reqwest client, for downloads and requests to other hosts.
+    ///
+    /// Like API requests, this waits for the rate limiter and retries timeouts, connection failures, 5xx and 429 responses.
+    async fn send_request(&self, request: impl Fn(&reqwest::Client) -> reqwest::RequestBuilder) -> Result<reqwest::Response, ApiError> {
+        let result = self.retry(|| request(self.transport.client()).send(), |result| match result {
+            Ok(resp) if resp.status().is_server_error() || resp.status() == StatusCode::TOO_MANY_REQUESTS => Some(
+                resp.headers().get(RETRY_AFTER).and_then(|v| v.to_str().ok()).and_then(|v| v.trim().parse().ok()).map(Duration::from_secs),
+            ),
+            Ok(_) => None,
+            Err(e) => (e.is_timeout() || e.is_connect()).then_some(None),
+        }).await;
+        Ok(result?)
+    }
+
+    async fn write_body(mut resp: reqwest::Response, file: &mut File) -> Result<u64, ApiError> {
+        let mut written = 0;
+        while let Some(chunk) = resp.chunk().await? {
+            file.write_all(&chunk).await?;
+            written += chunk.len() as u64;
+        }
+        file.flush().await?;
+        Ok(written)
+    }
+
+    /// Check each mod's `source_code_url` with a HEAD request, at most `concurrency` at a time.
+    ///
+    /// Mods without a source URL are skipped. Results are returned in completion order.
+    pub async fn check_source_links(&self, mods: &[DetailedMod], concurrency: usize) -> Vec<(u32, LinkStatus)> {
+        let targets = mods.iter().filter_map(|m| match m.source_code_url.as_deref().map(str::trim) {
+            Some(url) if !url.is_empty() => Some((m.mod_id, url.to_string())),
+            _ => None,
+        });
+
+        stream::iter(targets)
+            .map(|(mod_id, url)| async move {
+                let status = match self.send_request(|client| client.head(&url)).await {
+                    Ok(resp) if resp.status().is_success() => LinkStatus::Ok,
+                    Ok(resp) if matches!(resp.status().as_u16(), 404 | 410) => LinkStatus::NotFound,
+                    Ok(resp) => LinkStatus::Error(format!("HTTP status {}", resp.status())),
+                    Err(e) => LinkStatus::Error(e.to_string()),
+                };
+                (mod_id, status)
+            })
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await
+    }
+}
+
+/// Server-side filters for [`VintageStoryModDbApi::search`], defaults match no filtering.
+#[derive(Debug, Default)]
+struct SearchFilter {
+    /// Only mods by this user, 0 for any
+    user_id: u32,
+    /// Only mods with any of these tags, empty for any
+    tag_ids: Vec<u32>,
+    /// Only mods with a release for the game version with this tag id
+    game_version: Option<i64>,
+}
+
+/// Hands out evenly spaced send slots so requests don't exceed a rate.
+#[derive(Debug)]
+struct RateLimiter {
+    requests_per_second: f64,
+    interval: Duration,
+    next_slot: Mutex<Option<Instant>>,
+}
+
+impl RateLimiter {
+    /// One request per day, the slowest supported rate.
+    const MIN_REQUESTS_PER_SECOND: f64 = 1.0 / 86_400.0;
+
+    fn new(requests_per_second: f64) -> Self {
+        Self {
+            requests_per_second,
+            interval: Duration::try_from_secs_f64(1.0 / requests_per_second).unwrap_or(Duration::from_secs(86_400)),
+            next_slot: Mutex::new(None),
         }
+    }
+
+    /// Reserve the next slot, returning how long to wait until it.
+    fn reserve(&self) -> Duration {
+        let now = Instant::now();
+        let mut next_slot = self.next_slot.lock().unwrap();
+        let slot = next_slot.map_or(now, |next| next.max(now));
+        *next_slot = Some(slot + self.interval);
+        slot - now
+    }
+}
+
+/// The cached mods list, indexed by `asset_id`.
+#[derive(Debug)]
+struct ModsCache {
+    mods: Vec<SimpleMod>,
+    by_asset_id: HashMap<u32, usize>,
+    fetched: Instant,
+    validators: Validators,
+}
+
+impl ModsCache {
+    fn new(mods: Vec<SimpleMod>, validators: Validators) -> Self {
+        let by_asset_id = mods.iter().enumerate().map(|(i, m)| (m.asset_id, i)).collect();
+        Self { mods, by_asset_id, fetched: Instant::now(), validators }
+    }
 
-        Ok(current.clone())
+    fn by_asset_id(&self, asset_id: u32) -> Option<&SimpleMod> {
+        self.by_asset_id.get(&asset_id).map(|&i| &self.mods[i])
     }
 }
 
+/// A cached list endpoint response with when it was fetched.
+#[derive(Debug)]
+struct ListCache<I> {
+    items: Vec<I>,
+    fetched: Instant,
+    validators: Validators,
+}
+
+/// `ETag` and `Last-Modified` of a cached response, sent back so the server can answer `304 Not Modified`.
+#[derive(Debug, Clone, Default)]
+struct Validators {
+    etag: Option<String>,
+    last_modified: Option<String>,
+}
+
+impl Validators {
+    fn from_response(response: &TransportResponse) -> Self {
+        Self {
+            etag: response.header("etag").map(str::to_string),
+            last_modified: response.header("last-modified").map(str::to_string),
+        }
+    }
+
+    /// The conditional request headers, empty if nothing is known.
+    fn request_headers(&self) -> Vec<(&str, &str)> {
+        let mut headers = vec![];
+        if let Some(etag) = &self.etag {
+            headers.push(("If-None-Match", etag.as_str()));
+        }
+        if let Some(last_modified) = &self.last_modified {
+            headers.push(("If-Modified-Since", last_modified.as_str()));
+        }
+        headers
+    }
+}
+
+/// Cached detailed mods with when they were fetched, reachable by mod id and by alias.
+#[derive(Debug, Default)]
+struct ModDetailCache {
+    mods: HashMap<u32, (Instant, DetailedMod)>,
+    aliases: HashMap<String, u32>,
+}
+
+impl ModDetailCache {
+    /// Look up by numeric id, or by alias ignoring case.
+    fn get(&self, alias: &str) -> Option<&(Instant, DetailedMod)> {
+        let mod_id = match alias.parse() {
+            Ok(mod_id) => mod_id,
+            Err(_) => *self.aliases.get(&alias.to_ascii_lowercase())?,
+        };
+        self.mods.get(&mod_id)
+    }
+
+    fn insert(&mut self, mod_info: DetailedMod) {
+        if let Some(alias) = &mod_info.url_alias {
+            self.aliases.insert(alias.to_ascii_lowercase(), mod_info.mod_id);
+        }
+        self.mods.insert(mod_info.mod_id, (Instant::now(), mod_info));
+    }
+}
+
+/// Result of checking whether a link is still reachable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinkStatus {
+    /// The link answered with a success status
+    Ok,
+    /// The link answered with 404 or 410
+    NotFound,
+    /// Any other status, or the request failed
+    Error(String),
+}
+
+/// How many characters of a response body `ApiError::Decode` keeps.
+const BODY_SNIPPET_LEN: usize = 300;
+
+/// The start of a response body for error messages, cut at `BODY_SNIPPET_LEN` characters.
+fn body_snippet(body: &str) -> String {
+    match body.char_indices().nth(BODY_SNIPPET_LEN) {
+        Some((end, _)) => format!("{}...", &body[..end]),
+        None => body.to_string(),
+    }
+}
+
+/// Trim and lowercase a mod alias, rejecting anything that wouldn't stay a single `/mod/{alias}` path segment.
+fn normalize_alias(alias: &str) -> Result<String, ApiError> {
+    let alias = alias.trim();
+    if alias.is_empty() || alias.chars().any(|c| matches!(c, '/' | '\\' | '?' | '#' | '%') || c.is_whitespace() || c.is_control()) {
+        return Err(ApiError::Unexpected(format!("Invalid mod alias {:?}", alias)));
+    }
+    Ok(alias.to_lowercase())
+}
+
+/// Normalize names for comparison by keeping only letters and digits, lowercased.
+///
+/// Works on the compatibility decomposition, so accents are dropped (`Café` becomes `cafe`) and
+/// full-width or ligature forms match their plain letters. Other scripts are kept as they are.
+fn normalize(s: &str) -> String {
+    s.nfkd().filter(|c| c.is_alphanumeric()).flat_map(char::to_lowercase).collect()
+}
+
+/// Similarity of two normalized names from 0.0 to 1.0, see [`VintageStoryModDbApi::search_name_fuzzy`].
+fn fuzzy_score(query: &str, name: &str) -> f64 {
+    let query: Vec<char> = query.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let longest = query.len().max(name.len());
+    if longest == 0 {
+        return 0.0;
+    }
+
+    // Levenshtein distance, keeping one row of the table
+    let mut row: Vec<usize> = (0..=name.len()).collect();
+    for (i, q) in query.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, n) in name.iter().enumerate() {
+            let substitution = diagonal + usize::from(q != n);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+    let similarity = 1.0 - row[name.len()] as f64 / longest as f64;
+
+    let contains = query.len() <= name.len() && name.windows(query.len()).any(|w| w == query.as_slice());
+    let containment = if contains { 0.5 + 0.5 * query.len() as f64 / name.len() as f64 } else { 0.0 };
+    similarity.max(containment)
+}
+
 #[derive(Default, Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq)]
 pub enum SortBy {
     Trending,
@@ -263,6 +1613,188 @@ impl Display for SortBy {
     }
 }
 
+impl FromStr for SortBy {
+    type Err = ApiError;
+
+    /// Parse either a friendly name (`"downloads"`, `"released"`) or the raw query value (`"lastreleased"`), ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "trending" | "trendingpoints" => Ok(SortBy::Trending),
+            "downloads" => Ok(SortBy::Downloads),
+            "comments" => Ok(SortBy::Comments),
+            "name" => Ok(SortBy::Name),
+            "released" | "lastreleased" => Ok(SortBy::Released),
+            "created" => Ok(SortBy::Created),
+            _ => Err(ApiError::Unexpected(format!("Unknown sort order: {}", s))),
+        }
+    }
+}
+
+// Optional feature: request and cache metrics using the `metrics` facade
+#[cfg(feature = "metrics")]
+mod metrics_support {
+    use std::time::Duration;
+
+    /// `status` is the HTTP status of the last attempt, or `None` if no response was received.
+    pub(super) fn record_request(endpoint: &'static str, status: Option<u16>, elapsed: Duration, success: bool) {
+        let status = status.map_or("none".to_string(), |s| s.to_string());
+        metrics::counter!("vintagestory_api_requests_total", "endpoint" => endpoint, "status" => status).increment(1);
+        metrics::histogram!("vintagestory_api_request_duration_seconds", "endpoint" => endpoint).record(elapsed.as_secs_f64());
+        if !success {
+            metrics::counter!("vintagestory_api_errors_total", "endpoint" => endpoint).increment(1);
+        }
+    }
+
+    pub(super) fn record_cache_hit(cache: &'static str) {
+        metrics::counter!("vintagestory_api_cache_hits_total", "cache" => cache).increment(1);
+    }
+
+    pub(super) fn record_cache_miss(cache: &'static str) {
+        metrics::counter!("vintagestory_api_cache_misses_total", "cache" => cache).increment(1);
+    }
+}
+
+// Optional feature: lazily streamed results using `futures`
+#[cfg(feature = "stream")]
+mod stream_api {
+    use super::*;
+    use futures::stream::{self, Stream, StreamExt};
+
+    impl<T: Transport> VintageStoryModDbApi<T> {
+        /// Stream every comment across the whole mods list, tagged with its asset id.
+        ///
+        /// At most `concurrency` comment requests are in flight at once, and new ones are only started
+        /// as the consumer polls for more. Ordering is not guaranteed. Uses the mods cache if enabled.
+        pub fn all_comments_stream(&self, concurrency: usize) -> impl Stream<Item = Result<(u32, Comment), ApiError>> + '_ {
+            stream::once(self.get_mods()).flat_map(move |mods| match mods {
+                Ok(mods) => stream::iter(mods)
+                    .map(move |m| async move { (m.asset_id, self.get_comments(m.asset_id).await) })
+                    .buffer_unordered(concurrency.max(1))
+                    .flat_map(|(asset_id, comments)| match comments {
+                        Ok(comments) => stream::iter(comments).map(move |c| Ok((asset_id, c))).left_stream(),
+                        Err(e) => stream::once(async { Err(e) }).right_stream(),
+                    })
+                    .left_stream(),
+                Err(e) => stream::once(async { Err(e) }).right_stream(),
+            })
+        }
+
+        /// Stream the details of every mod in the mods list, fetching them from `/mod/{id}` as the consumer polls.
+        ///
+        /// At most `concurrency` requests are in flight at once. Ordering is not guaranteed, mods are yielded as
+        /// their requests finish. A failure to get the mods list is yielded as the only item. Uses the caches if enabled.
+        pub fn stream_detailed_mods(&self, concurrency: usize) -> impl Stream<Item = Result<DetailedMod, ApiError>> + '_ {
+            stream::once(self.get_mods()).flat_map(move |mods| match mods {
+                Ok(mods) => stream::iter(mods)
+                    .map(move |m| self.get_mod(m.mod_id))
+                    .buffer_unordered(concurrency.max(1))
+                    .left_stream(),
+                Err(e) => stream::once(async { Err(e) }).right_stream(),
+            })
+        }
+    }
+}
+
+// Optional feature: regex search using `regex`
+#[cfg(feature = "regex")]
+mod regex_api {
+    use super::*;
+    use regex::Regex;
+
+    impl<T: Transport> VintageStoryModDbApi<T> {
+        /// Search mods whose summary matches a regular expression.
+        ///
+        /// Uses cache if enabled. Mods without a summary never match. Returns `ApiError::Unexpected` if the pattern is invalid.
+        pub async fn search_summary_regex(&self, pattern: &str) -> Result<Vec<SimpleMod>, ApiError> {
+            let regex = Regex::new(pattern).map_err(|e| ApiError::Unexpected(format!("Invalid regex {}: {}", pattern, e)))?;
+            let mods = self.get_mods().await?;
+
+            Ok(mods.into_iter().filter(|m| m.summary.as_deref().is_some_and(|s| regex.is_match(s))).collect())
+        }
+    }
+}
+
+// Optional feature: date-based queries using `chrono`
+#[cfg(feature = "chrono")]
+mod chrono_api {
+    use super::*;
+    use chrono::{DateTime, Days, NaiveDate, Utc};
+
+    impl<T: Transport> VintageStoryModDbApi<T> {
+        /// Count an asset's comments per day, week or month, keyed by the first day of each bucket.
+        ///
+        /// Comments with an unparseable `created` timestamp are left out. Empty buckets are not included.
+        pub async fn comment_histogram(&self, asset_id: u32, bucket: Bucket) -> Result<BTreeMap<NaiveDate, usize>, ApiError> {
+            let comments = self.get_comments(asset_id).await?;
+            let mut histogram = BTreeMap::new();
+            for created in comments.iter().filter_map(|c| c.created_at().ok()) {
+                *histogram.entry(bucket.start_of(created.date_naive())).or_default() += 1;
+            }
+            Ok(histogram)
+        }
+
+        /// Get mods whose latest release was in the last `days` days, newest first.
+        ///
+        /// Mods with an unparseable `last_released` are left out. Uses cache if enabled.
+        pub async fn get_mods_released_within(&self, days: u32) -> Result<Vec<SimpleMod>, ApiError> {
+            let cutoff = Utc::now() - Days::new(days as u64);
+            let mut recent: Vec<_> = self.get_mods().await?
+                .into_iter()
+                .filter_map(|m| m.last_released_at().ok().filter(|released| *released >= cutoff).map(|released| (released, m)))
+                .collect();
+            recent.sort_by_key(|(released, _)| std::cmp::Reverse(*released));
+            Ok(recent.into_iter().map(|(_, m)| m).collect())
+        }
+
+        /// Get mods with a release after `since`, in the mods list's order.
+        ///
+        /// Mods with an unparseable `last_released` are skipped with a logged warning. Uses cache if enabled.
+        pub async fn get_mods_updated_since(&self, since: DateTime<Utc>) -> Result<Vec<SimpleMod>, ApiError> {
+            let mods = self.get_mods().await?;
+            Ok(mods.into_iter()
+                .filter(|m| match m.last_released_at() {
+                    Ok(released) => released > since,
+                    Err(e) => {
+                        log::warn!("Skipping mod {}: {}", m.mod_id, e);
+                        false
+                    }
+                })
+                .collect())
+        }
+    }
+}
+
+// Optional feature: reading release archives using `zip`
+#[cfg(feature = "zip")]
+mod zip_api {
+    use super::*;
+    use std::io::{Cursor, Read};
+    use zip::ZipArchive;
+
+    impl VintageStoryModDbApi {
+        /// Download a release and read the dependencies declared in its `modinfo.json`.
+        ///
+        /// The archive is kept in memory. Returns `ApiError::Unexpected` if it isn't a zip or has no `modinfo.json`.
+        pub async fn get_dependencies(&self, release: &DetailedModRelease) -> Result<Vec<ModDependency>, ApiError> {
+            let resp = self.send_request(|client| client.get(release.download_link())).await?.error_for_status()?;
+            let bytes = resp.bytes().await?;
+
+            let invalid = |e: zip::result::ZipError| ApiError::Unexpected(format!("Release {} is not a valid zip: {}", release.release_id, e));
+            let mut archive = ZipArchive::new(Cursor::new(bytes)).map_err(invalid)?;
+            // Prefer the top-level file, some archives wrap everything in a folder
+            let name = archive.file_names()
+                .filter(|name| name.rsplit('/').next().is_some_and(|file| file.eq_ignore_ascii_case("modinfo.json")))
+                .min_by_key(|name| name.matches('/').count())
+                .map(str::to_string)
+                .ok_or_else(|| ApiError::Unexpected(format!("Release {} has no modinfo.json", release.release_id)))?;
+
+            let mut json = String::new();
+            archive.by_name(&name).map_err(invalid)?.read_to_string(&mut json)?;
+            ModDependency::parse_modinfo(&json)
+        }
+    }
+}
+
 // Optional feature: random selection functions using `rand`
 #[cfg(feature = "random")]
 mod random_api {
@@ -270,20 +1802,29 @@ mod random_api {
     use super::*;
     use rand::rng;
 
-    impl VintageStoryModDbApi {
-        /// Get a random mod from the mods list.
+    /// Picking from an empty list returns `ApiError::Empty` naming what was empty.
+    impl<T: Transport> VintageStoryModDbApi<T> {
+        /// Get a random mod from the mods list, with its details.
         pub async fn get_random_mod(&self) -> Result<DetailedMod, ApiError> {
+            let selected = self.get_random_simple_mod().await?;
+            self.get_mod(selected.mod_id).await
+        }
+
+        /// Get a random mod from the mods list, without fetching its details.
+        ///
+        /// Uses cache if enabled, so repeated picks cost no requests.
+        pub async fn get_random_simple_mod(&self) -> Result<SimpleMod, ApiError> {
             let mods = self.get_mods().await?;
             let mut rng = rng();
-            let selected = mods.choose(&mut rng).ok_or_else(|| ApiError::Unexpected("No mods found".into()))?;
-            self.get_mod(selected.mod_id).await
+            let selected = mods.choose(&mut rng).ok_or_else(|| ApiError::Empty("mods"))?;
+            Ok(selected.clone())
         }
 
         /// Get a random tag.
         pub async fn get_random_tag(&self) -> Result<Tag, ApiError> {
             let tags = self.get_tags().await?;
             let mut rng = rng();
-            let selected = tags.choose(&mut rng).ok_or_else(|| ApiError::Unexpected("No tags found".into()))?;
+            let selected = tags.choose(&mut rng).ok_or_else(|| ApiError::Empty("tags"))?;
             Ok(selected.clone())
         }
 
@@ -291,7 +1832,7 @@ mod random_api {
         pub async fn get_random_author(&self) -> Result<Author, ApiError> {
             let authors = self.get_authors().await?;
             let mut rng = rng();
-            let selected = authors.choose(&mut rng).ok_or_else(|| ApiError::Unexpected("No authors found".into()))?;
+            let selected = authors.choose(&mut rng).ok_or_else(|| ApiError::Empty("authors"))?;
             Ok(selected.clone())
         }
 
@@ -299,18 +1840,15 @@ mod random_api {
         pub async fn get_random_game_version(&self) -> Result<GameVersion, ApiError> {
             let versions = self.get_game_versions().await?;
             let mut rng = rng();
-            let selected = versions.choose(&mut rng).ok_or_else(|| ApiError::Unexpected("No versions found".into()))?;
+            let selected = versions.choose(&mut rng).ok_or_else(|| ApiError::Empty("game versions"))?;
             Ok(selected.clone())
         }
 
         /// Get a random comment for an asset (or None if no comments exist).
         pub async fn get_random_comment(&self, asset_id: u32) -> Result<Option<Comment>, ApiError> {
             let comments = self.get_comments(asset_id).await?;
-            if comments.is_empty() {
-                return Ok(None);
-            }
             let mut rng = rng();
-            Ok(Some(comments.choose(&mut rng).unwrap().clone()))
+            Ok(comments.choose(&mut rng).cloned())
         }
     }
 }
@@ -0,0 +1,271 @@
+//! Synchronous client for tools that don't want an async runtime.
+//!
+//! [`VintageStoryModDbApiBlocking`] wraps the async client around a `reqwest::blocking::Client`, so every
+//! request completes while its future is polled and the methods can simply block on it.
+//! Like `reqwest::blocking`, it must not be used from within an async runtime.
+
+use crate::api::{SortBy, VintageStoryModDbApi};
+use crate::transport::{header_pairs, Transport, TransportError, TransportResponse};
+use crate::{ApiConfig, ApiError, RetryPolicy, Author, Comment, DetailedMod, DetailedModRelease, GameVersion, SimpleMod, Tag};
+use futures::executor::block_on;
+use std::time::Duration;
+
+/// A [`Transport`] sending requests with a `reqwest::blocking::Client`.
+#[derive(Debug, Clone, Default)]
+pub struct BlockingTransport {
+    client: reqwest::blocking::Client,
+}
+
+impl BlockingTransport {
+    /// Create a transport using an existing `reqwest::blocking::Client`.
+    pub fn new(client: reqwest::blocking::Client) -> Self {
+        Self { client }
+    }
+}
+
+impl Transport for BlockingTransport {
+    async fn get(&self, url: &str) -> Result<(u16, String), TransportError> {
+        let resp = self.client.get(url).send()?;
+        let status = resp.status().as_u16();
+        Ok((status, resp.text()?))
+    }
+
+    async fn get_response(&self, url: &str) -> Result<TransportResponse, TransportError> {
+        self.get_with_headers(url, &[]).await
+    }
+
+    async fn get_with_headers(&self, url: &str, headers: &[(&str, &str)]) -> Result<TransportResponse, TransportError> {
+        let mut request = self.client.get(url);
+        for (name, value) in headers {
+            request = request.header(*name, *value);
+        }
+        let resp = request.send()?;
+        let status = resp.status().as_u16();
+        let headers = header_pairs(resp.headers());
+        Ok(TransportResponse { status, body: resp.text()?, headers })
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        std::thread::sleep(duration)
+    }
+}
+
+/// Blocking counterpart of [`VintageStoryModDbApi`], mirroring its request, cache and release methods minus `async`.
+///
+/// Configure timeouts and the other builder settings with
+/// [`VintageStoryModDbApiBuilder::build_blocking`](crate::VintageStoryModDbApiBuilder::build_blocking).
+/// Methods without a blocking mirror can be called on [`Self::inner`] with `futures::executor::block_on`.
+#[derive(Debug)]
+pub struct VintageStoryModDbApiBlocking {
+    inner: VintageStoryModDbApi<BlockingTransport>,
+}
+
+impl VintageStoryModDbApiBlocking {
+    /// Create a new blocking client, see [`VintageStoryModDbApi::new`].
+    pub fn new(enable_cache: bool) -> Self {
+        VintageStoryModDbApi::builder().enable_cache(enable_cache).build_blocking().expect("failed to build the default HTTP client")
+    }
+
+    /// Create a new blocking client sending requests to `base_url`, see [`VintageStoryModDbApi::with_base_url`].
+    pub fn with_base_url(base_url: impl Into<String>, enable_cache: bool) -> Self {
+        VintageStoryModDbApi::builder()
+            .base_url(base_url)
+            .enable_cache(enable_cache)
+            .build_blocking()
+            .expect("failed to build the default HTTP client")
+    }
+
+    /// Create a new blocking client using an existing `reqwest::blocking::Client`.
+    pub fn with_client(client: reqwest::blocking::Client, enable_cache: bool) -> Self {
+        Self { inner: VintageStoryModDbApi::with_transport(BlockingTransport::new(client), enable_cache) }
+    }
+
+    pub(crate) fn from_inner(inner: VintageStoryModDbApi<BlockingTransport>) -> Self {
+        Self { inner }
+    }
+
+    /// Retry transient failures according to `policy`, see [`VintageStoryModDbApi::with_retry_policy`].
+    pub fn with_retry_policy(self, policy: RetryPolicy) -> Self {
+        Self { inner: self.inner.with_retry_policy(policy) }
+    }
+
+    /// Expire cached results after `ttl`, see [`VintageStoryModDbApi::with_cache_ttl`].
+    pub fn with_cache_ttl(self, ttl: Duration) -> Self {
+        Self { inner: self.inner.with_cache_ttl(ttl) }
+    }
+
+    /// Send at most `requests_per_second` requests, see [`VintageStoryModDbApi::with_rate_limit`].
+    pub fn with_rate_limit(self, requests_per_second: f64) -> Self {
+        Self { inner: self.inner.with_rate_limit(requests_per_second) }
+    }
+
+    /// Build asset URLs against `host`, see [`VintageStoryModDbApi::with_asset_host`].
+    pub fn with_asset_host(self, host: impl Into<String>) -> Self {
+        Self { inner: self.inner.with_asset_host(host) }
+    }
+
+    /// The wrapped async client.
+    pub fn inner(&self) -> &VintageStoryModDbApi<BlockingTransport> {
+        &self.inner
+    }
+
+    /// See [`VintageStoryModDbApi::config`].
+    pub fn config(&self) -> ApiConfig {
+        self.inner.config()
+    }
+
+    /// See [`VintageStoryModDbApi::get_mods`].
+    pub fn get_mods(&self) -> Result<Vec<SimpleMod>, ApiError> {
+        block_on(self.inner.get_mods())
+    }
+
+    /// See [`VintageStoryModDbApi::refresh_mods_cache`].
+    pub fn refresh_mods_cache(&self) -> Result<bool, ApiError> {
+        block_on(self.inner.refresh_mods_cache())
+    }
+
+    /// See [`VintageStoryModDbApi::get_mod`].
+    pub fn get_mod(&self, mod_id: u32) -> Result<DetailedMod, ApiError> {
+        block_on(self.inner.get_mod(mod_id))
+    }
+
+    /// See [`VintageStoryModDbApi::get_mod_from_alias`].
+    pub fn get_mod_from_alias(&self, alias: impl AsRef<str>) -> Result<DetailedMod, ApiError> {
+        block_on(self.inner.get_mod_from_alias(alias))
+    }
+
+    /// See [`VintageStoryModDbApi::get_mod_from_alias_cached`].
+    pub fn get_mod_from_alias_cached(&self, alias: impl AsRef<str>) -> Result<DetailedMod, ApiError> {
+        block_on(self.inner.get_mod_from_alias_cached(alias))
+    }
+
+    /// See [`VintageStoryModDbApi::search_mods`].
+    pub fn search_mods(&self, query: impl AsRef<str>, ascending: bool, sort_by: impl Into<Option<SortBy>>) -> Result<Vec<SimpleMod>, ApiError> {
+        block_on(self.inner.search_mods(query, ascending, sort_by))
+    }
+
+    /// See [`VintageStoryModDbApi::search_name`].
+    pub fn search_name(&self, query: impl AsRef<str>) -> Result<Vec<SimpleMod>, ApiError> {
+        block_on(self.inner.search_name(query))
+    }
+
+    /// See [`VintageStoryModDbApi::search_mod_id`].
+    pub fn search_mod_id(&self, query: impl AsRef<str>) -> Result<Vec<SimpleMod>, ApiError> {
+        block_on(self.inner.search_mod_id(query))
+    }
+
+    /// See [`VintageStoryModDbApi::get_tags`].
+    pub fn get_tags(&self) -> Result<Vec<Tag>, ApiError> {
+        block_on(self.inner.get_tags())
+    }
+
+    /// See [`VintageStoryModDbApi::refresh_tags_cache`].
+    pub fn refresh_tags_cache(&self) -> Result<bool, ApiError> {
+        block_on(self.inner.refresh_tags_cache())
+    }
+
+    /// See [`VintageStoryModDbApi::get_authors`].
+    pub fn get_authors(&self) -> Result<Vec<Author>, ApiError> {
+        block_on(self.inner.get_authors())
+    }
+
+    /// See [`VintageStoryModDbApi::refresh_authors_cache`].
+    pub fn refresh_authors_cache(&self) -> Result<bool, ApiError> {
+        block_on(self.inner.refresh_authors_cache())
+    }
+
+    /// See [`VintageStoryModDbApi::get_game_versions`].
+    pub fn get_game_versions(&self) -> Result<Vec<GameVersion>, ApiError> {
+        block_on(self.inner.get_game_versions())
+    }
+
+    /// See [`VintageStoryModDbApi::refresh_game_versions_cache`].
+    pub fn refresh_game_versions_cache(&self) -> Result<bool, ApiError> {
+        block_on(self.inner.refresh_game_versions_cache())
+    }
+
+    /// See [`VintageStoryModDbApi::get_comments`].
+    pub fn get_comments(&self, asset_id: u32) -> Result<Vec<Comment>, ApiError> {
+        block_on(self.inner.get_comments(asset_id))
+    }
+
+    /// See [`VintageStoryModDbApi::clear_mods_cache`].
+    pub fn clear_mods_cache(&self) {
+        self.inner.clear_mods_cache()
+    }
+
+    /// See [`VintageStoryModDbApi::clear_authors_cache`].
+    pub fn clear_authors_cache(&self) {
+        self.inner.clear_authors_cache()
+    }
+
+    /// See [`VintageStoryModDbApi::clear_tags_cache`].
+    pub fn clear_tags_cache(&self) {
+        self.inner.clear_tags_cache()
+    }
+
+    /// See [`VintageStoryModDbApi::clear_game_versions_cache`].
+    pub fn clear_game_versions_cache(&self) {
+        self.inner.clear_game_versions_cache()
+    }
+
+    /// See [`VintageStoryModDbApi::clear_mod_detail_cache`].
+    pub fn clear_mod_detail_cache(&self) {
+        self.inner.clear_mod_detail_cache()
+    }
+
+    /// See [`VintageStoryModDbApi::clear_all_caches`].
+    pub fn clear_all_caches(&self) {
+        self.inner.clear_all_caches()
+    }
+
+    /// See [`VintageStoryModDbApi::get_most_recent_release`].
+    pub fn get_most_recent_release(&self, mod_id: u32) -> Result<DetailedModRelease, ApiError> {
+        block_on(self.inner.get_most_recent_release(mod_id))
+    }
+
+    /// See [`VintageStoryModDbApi::get_most_recent_release_from_alias`].
+    pub fn get_most_recent_release_from_alias(&self, alias: impl AsRef<str>) -> Result<DetailedModRelease, ApiError> {
+        block_on(self.inner.get_most_recent_release_from_alias(alias))
+    }
+
+    /// See [`VintageStoryModDbApi::get_most_recent_release_from_alias_with_version`].
+    pub fn get_most_recent_release_from_alias_with_version(&self, alias: impl AsRef<str>, version: impl AsRef<str>) -> Result<DetailedModRelease, ApiError> {
+        block_on(self.inner.get_most_recent_release_from_alias_with_version(alias, version))
+    }
+
+    /// See [`VintageStoryModDbApi::get_most_recent_release_with_version`].
+    pub fn get_most_recent_release_with_version(&self, mod_id: u32, version: impl AsRef<str>) -> Result<DetailedModRelease, ApiError> {
+        block_on(self.inner.get_most_recent_release_with_version(mod_id, version))
+    }
+
+    /// See [`VintageStoryModDbApi::get_releases_for_version`].
+    pub fn get_releases_for_version(&self, mod_id: u32, version: impl AsRef<str>) -> Result<Vec<DetailedModRelease>, ApiError> {
+        block_on(self.inner.get_releases_for_version(mod_id, version))
+    }
+
+    /// See [`VintageStoryModDbApi::get_mod_release_with_version`].
+    pub fn get_mod_release_with_version(&self, mod_id: u32, version: impl AsRef<str>) -> Result<DetailedModRelease, ApiError> {
+        block_on(self.inner.get_mod_release_with_version(mod_id, version))
+    }
+
+    /// See [`VintageStoryModDbApi::get_recommended_release`].
+    pub fn get_recommended_release(&self, mod_id: u32) -> Result<Option<DetailedModRelease>, ApiError> {
+        block_on(self.inner.get_recommended_release(mod_id))
+    }
+
+    /// See [`VintageStoryModDbApi::get_mod_release_with_version_from_alias`].
+    pub fn get_mod_release_with_version_from_alias(&self, alias: impl AsRef<str>, version: impl AsRef<str>) -> Result<DetailedModRelease, ApiError> {
+        block_on(self.inner.get_mod_release_with_version_from_alias(alias, version))
+    }
+
+    /// See [`VintageStoryModDbApi::get_most_recent_game_version`].
+    pub fn get_most_recent_game_version(&self) -> Result<GameVersion, ApiError> {
+        block_on(self.inner.get_most_recent_game_version())
+    }
+
+    /// See [`VintageStoryModDbApi::get_most_recent_stable_game_version`].
+    pub fn get_most_recent_stable_game_version(&self) -> Result<GameVersion, ApiError> {
+        block_on(self.inner.get_most_recent_stable_game_version())
+    }
+}
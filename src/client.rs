@@ -0,0 +1,71 @@
+//! Trait over the API's read methods, for code that should work against a fake in tests.
+//!
+//! Depend on [`ModDbClient`] instead of [`VintageStoryModDbApi`] and substitute your own implementation
+//! where real requests aren't wanted. The concrete client keeps its inherent methods, which take more
+//! flexible arguments and include the helpers built on top of these.
+
+use crate::api::SortBy;
+use crate::transport::Transport;
+use crate::{ApiError, Author, Comment, DetailedMod, GameVersion, SimpleMod, Tag, VintageStoryModDbApi};
+use std::future::Future;
+
+/// The read endpoints of the mod DB, implemented by [`VintageStoryModDbApi`].
+pub trait ModDbClient: Send + Sync {
+    /// All mods, see [`VintageStoryModDbApi::get_mods`].
+    fn get_mods(&self) -> impl Future<Output = Result<Vec<SimpleMod>, ApiError>> + Send;
+
+    /// A mod by id, see [`VintageStoryModDbApi::get_mod`].
+    fn get_mod(&self, mod_id: u32) -> impl Future<Output = Result<DetailedMod, ApiError>> + Send;
+
+    /// A mod by alias or id string, see [`VintageStoryModDbApi::get_mod_from_alias`].
+    fn get_mod_from_alias(&self, alias: &str) -> impl Future<Output = Result<DetailedMod, ApiError>> + Send;
+
+    /// Search the site, see [`VintageStoryModDbApi::search_mods`].
+    fn search_mods(&self, query: &str, ascending: bool, sort_by: Option<SortBy>) -> impl Future<Output = Result<Vec<SimpleMod>, ApiError>> + Send;
+
+    /// All tags, see [`VintageStoryModDbApi::get_tags`].
+    fn get_tags(&self) -> impl Future<Output = Result<Vec<Tag>, ApiError>> + Send;
+
+    /// All authors, see [`VintageStoryModDbApi::get_authors`].
+    fn get_authors(&self) -> impl Future<Output = Result<Vec<Author>, ApiError>> + Send;
+
+    /// All game versions, see [`VintageStoryModDbApi::get_game_versions`].
+    fn get_game_versions(&self) -> impl Future<Output = Result<Vec<GameVersion>, ApiError>> + Send;
+
+    /// An asset's comments, see [`VintageStoryModDbApi::get_comments`].
+    fn get_comments(&self, asset_id: u32) -> impl Future<Output = Result<Vec<Comment>, ApiError>> + Send;
+}
+
+impl<T: Transport> ModDbClient for VintageStoryModDbApi<T> {
+    async fn get_mods(&self) -> Result<Vec<SimpleMod>, ApiError> {
+        VintageStoryModDbApi::get_mods(self).await
+    }
+
+    async fn get_mod(&self, mod_id: u32) -> Result<DetailedMod, ApiError> {
+        VintageStoryModDbApi::get_mod(self, mod_id).await
+    }
+
+    async fn get_mod_from_alias(&self, alias: &str) -> Result<DetailedMod, ApiError> {
+        VintageStoryModDbApi::get_mod_from_alias(self, alias).await
+    }
+
+    async fn search_mods(&self, query: &str, ascending: bool, sort_by: Option<SortBy>) -> Result<Vec<SimpleMod>, ApiError> {
+        VintageStoryModDbApi::search_mods(self, query, ascending, sort_by).await
+    }
+
+    async fn get_tags(&self) -> Result<Vec<Tag>, ApiError> {
+        VintageStoryModDbApi::get_tags(self).await
+    }
+
+    async fn get_authors(&self) -> Result<Vec<Author>, ApiError> {
+        VintageStoryModDbApi::get_authors(self).await
+    }
+
+    async fn get_game_versions(&self) -> Result<Vec<GameVersion>, ApiError> {
+        VintageStoryModDbApi::get_game_versions(self).await
+    }
+
+    async fn get_comments(&self, asset_id: u32) -> Result<Vec<Comment>, ApiError> {
+        VintageStoryModDbApi::get_comments(self, asset_id).await
+    }
+}
@@ -1,23 +1,83 @@
 //! API error type for the VintageStory API client.
 
+use crate::transport::TransportError;
 use std::io::Error;
 use thiserror::Error;
 
 /// Represents all possible errors that can occur when using the VintageStory API client.
 #[derive(Error, Debug)]
 pub enum ApiError {
-    /// An HTTP-level error (network failure, timeout, deserialization failure, etc)
+    /// An HTTP-level error (network failure, timeout, etc)
+    #[cfg(feature = "reqwest")]
     #[error("HTTP error: {0}")]
     Http(#[from] reqwest::Error),
 
+    /// A custom transport failed to complete the request
+    #[error("Transport error: {0}")]
+    Transport(TransportError),
+
+    /// The server answered with an unsuccessful HTTP status and no usable body
+    #[error("HTTP status {status} from {endpoint}")]
+    HttpStatus { status: u16, endpoint: String },
+
+    /// The requested resource doesn't exist, e.g. an unknown or removed mod.
+    /// Returned for HTTP 404 responses and for a `statuscode` of `"404"` in the body
+    #[error("Not found: {endpoint}")]
+    NotFound { endpoint: String },
+
+    /// The API reported a `statuscode` other than `"200"` in the response body
+    #[error("API status {code} from {endpoint}")]
+    Status { code: String, endpoint: String },
+
+    /// An API response body could not be deserialized, e.g. because the API changed shape.
+    /// Holds the start of the body, truncated to a few hundred characters
+    #[error("Could not decode response from {endpoint}: {source} (body: {body_snippet})")]
+    Decode { endpoint: String, body_snippet: String, source: serde_json::Error },
+
+    /// Other JSON could not be deserialized, such as a snapshot or `modinfo.json`
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// There was nothing to pick from, e.g. a random mod from an empty catalog. Holds what was empty.
+    #[error("No {0} found")]
+    Empty(&'static str),
+
     /// Any unexpected non-HTTP error
     #[error("Unexpected API error: {0}")]
     Unexpected(String),
 }
 
+impl ApiError {
+    /// Whether the error is likely temporary (timeouts, connection failures, 5xx or 429 responses),
+    /// so retrying later or falling back to cached data makes sense.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            #[cfg(feature = "reqwest")]
+            ApiError::Http(e) => {
+                e.is_timeout()
+                    || e.is_connect()
+                    || e.status().is_some_and(|s| s.is_server_error() || s.as_u16() == 429)
+            }
+            ApiError::Transport(e) => e.is_transient(),
+            ApiError::HttpStatus { status, .. } => *status >= 500 || *status == 429,
+            ApiError::Status { code, .. } => code.parse::<u16>().is_ok_and(|code| code >= 500 || code == 429),
+            ApiError::NotFound { .. } | ApiError::Decode { .. } | ApiError::Json(_) | ApiError::Empty(_) | ApiError::Unexpected(_) => false,
+        }
+    }
+}
+
+impl From<TransportError> for ApiError {
+    fn from(value: TransportError) -> Self {
+        match value {
+            #[cfg(feature = "reqwest")]
+            TransportError::Reqwest(e) => Self::Http(e),
+            other => Self::Transport(other),
+        }
+    }
+}
 
 impl From<std::io::Error> for ApiError {
     fn from(value: Error) -> Self {
         Self::Unexpected(value.to_string())
     }
-}
\ No newline at end of file
+}
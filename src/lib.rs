@@ -6,12 +6,38 @@
 //! Features:
 //! - Fetch mods, detailed mod info, authors, tags, game versions, comments
 //! - Optional in-memory caching
-//! - Optional random selection (via `rand` feature)
+//! - A [`ModDbClient`] trait to substitute fakes in tests
+//! - Configurable retries with exponential backoff for transient failures
+//! - Pluggable HTTP [`Transport`], with a `reqwest` implementation behind the default `reqwest` feature, using rustls (`rustls-tls`, default) or the platform TLS (`native-tls`)
+//! - Optional random selection (via `random` feature)
+//! - Optional lazily streamed results (via `stream` feature)
+//! - Optional regex search over summaries (via `regex` feature)
+//! - Optional request, latency, error and cache metrics (via `metrics` feature)
+//! - Optional semver matching of mod releases (via `semver` feature)
+//! - Optional typed timestamps and date-based queries (via `chrono` feature)
+//! - Optional synchronous client (via `blocking` feature)
+//! - Optional reading of release dependencies from `modinfo.json` (via `zip` feature)
+//!
+//! The API is read-only and has no authentication. Following mods and posting comments need a logged-in session
+//! on the site and have no API endpoints, so the client has no auth token, follow or comment posting support,
+//! only the public `follows` counts and [`VintageStoryModDbApi::get_comments`].
 
 pub mod api;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+pub mod client;
 pub mod error;
 pub mod models;
+pub mod retry;
+pub mod transport;
 
-pub use api::VintageStoryModDbApi;
+pub use api::{ApiConfig, VintageStoryModDbApi};
+#[cfg(feature = "reqwest")]
+pub use api::VintageStoryModDbApiBuilder;
+#[cfg(feature = "blocking")]
+pub use blocking::VintageStoryModDbApiBlocking;
+pub use client::ModDbClient;
 pub use error::ApiError;
 pub use models::*;
+pub use retry::RetryPolicy;
+pub use transport::{Transport, TransportError, TransportResponse};
@@ -1,7 +1,10 @@
 //! All models returned by the VintageStory Web Mod API.
 
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
-use serde::{de, Deserialize, Deserializer};
+use std::time::Duration;
+use serde::{de, Deserialize, Deserializer, Serialize};
 use serde::de::Visitor;
 
 /// Top-level response for `/mods`
@@ -54,32 +57,323 @@ pub(crate) struct CommentsResponse {
     pub comments: Vec<Comment>,
 }
 
-/// Simplified mod object returned by `/mods`
+/// Just the `statuscode` of a response, for bodies that don't match the expected shape
 #[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+pub(crate) struct StatusResponse {
+    #[serde(rename(deserialize = "statuscode"))]
+    pub status_code: String,
+}
+
+/// A top-level response carrying the API's own `statuscode`
+pub(crate) trait ApiResponse {
+    fn status_code(&self) -> &str;
+}
+
+macro_rules! impl_api_response {
+    ($($response:ty),*) => {
+        $(impl ApiResponse for $response {
+            fn status_code(&self) -> &str {
+                &self.status_code
+            }
+        })*
+    };
+}
+
+impl_api_response!(StatusResponse, ModsResponse, ModResponse, TagsResponse, AuthorsResponse, GameVersionsResponse, CommentsResponse);
+
+/// Simplified mod object returned by `/mods`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct SimpleMod {
-    #[serde(rename(deserialize = "modid"))]
+    #[serde(rename = "modid")]
     pub mod_id: u32,
-    #[serde(rename(deserialize = "assetid"))]
+    #[serde(rename = "assetid")]
     pub asset_id: u32,
     pub downloads: u32,
     pub follows: u32,
-    #[serde(rename(deserialize = "trendingpoints"))]
+    #[serde(rename = "trendingpoints")]
     pub trending_points: u32,
     pub comments: u32,
     pub name: String,
     pub summary: Option<String>,
-    #[serde(rename(deserialize = "modidstrs"))]
+    #[serde(rename = "modidstrs")]
     pub mod_id_strs: Vec<String>,
     pub author: String,
-    #[serde(rename(deserialize = "urlalias"))]
+    #[serde(rename = "urlalias")]
     pub url_alias: Option<String>,
+    #[serde(default)]
     pub side: String,
-    #[serde(rename(deserialize = "type"))]
+    #[serde(rename = "type", default)]
     pub mod_type: String,
     pub logo: Option<String>,
     pub tags: Vec<String>,
-    #[serde(rename(deserialize = "lastreleased"))]
+    #[serde(rename = "lastreleased")]
     pub last_released: String,
+    /// Fields sent by the API that this struct doesn't know about yet, kept so they survive re-serialization.
+    #[serde(flatten, skip_serializing_if = "serde_json::Map::is_empty")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl SimpleMod {
+    /// Comments per download, useful for spotting discussion-heavy mods.
+    ///
+    /// Mods without any downloads are treated as having one, so this never divides by zero.
+    pub fn engagement_ratio(&self) -> f64 {
+        self.comments as f64 / self.downloads.max(1) as f64
+    }
+
+    /// The summary as plain text for cards, at most `max_len` characters.
+    ///
+    /// HTML is stripped and whitespace collapsed, see [`strip_html`]. Longer summaries are cut at the last
+    /// word boundary that fits and end with `…`. Mods without a summary, or a `max_len` of 0, give an empty string.
+    pub fn summary_preview(&self, max_len: usize) -> String {
+        if max_len == 0 {
+            return String::new();
+        }
+        let text = strip_html(self.summary.as_deref().unwrap_or_default());
+        if text.chars().count() <= max_len {
+            return text;
+        }
+        let budget = max_len.saturating_sub(1);
+        let cut = text.char_indices().nth(budget).map(|(i, _)| i).unwrap_or(text.len());
+        let head = &text[..cut];
+        // Only break at a space when the cut landed mid-word
+        let head = if text[cut..].starts_with(' ') {
+            head
+        } else {
+            head.rfind(' ').map(|i| &head[..i]).unwrap_or(head)
+        };
+        format!("{}…", head.trim_end())
+    }
+
+    /// The typed `side` value.
+    pub fn side(&self) -> ModSide {
+        ModSide::from(self.side.as_str())
+    }
+
+    /// The typed `mod_type` value.
+    pub fn mod_type(&self) -> ModType {
+        ModType::from(self.mod_type.as_str())
+    }
+
+    /// Absolute URL of the mod's logo on the mod DB CDN, or `None` if it only has the site's placeholder.
+    pub fn logo_url(&self) -> Option<String> {
+        self.logo.as_deref()
+            .map(str::trim)
+            .filter(|logo| is_custom_logo(logo))
+            .map(|logo| resolve_url(ASSET_HOST, logo))
+    }
+}
+
+/// Convert mod description HTML to plain text.
+///
+/// Tags are removed, the common entities (`&amp;`, `&lt;`, `&gt;`, `&quot;`, `&#39;`, `&nbsp;`) and numeric
+/// entities (`&#8217;`, `&#x2019;`) decoded and runs of whitespace collapsed to a single space. A `<` only
+/// starts a tag when followed by a letter, `/` or `!`, so plain text like `a < b` is kept.
+pub fn strip_html(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    let mut chars = html.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '<' if !in_tag && chars.peek().is_some_and(|&next| next.is_ascii_alphabetic() || next == '/' || next == '!') => in_tag = true,
+            // Tags like `<br>` and `</p>` separate words
+            '>' if in_tag => {
+                in_tag = false;
+                text.push(' ');
+            }
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    decode_entities(&text).split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Decode the entities [`strip_html`] knows in one pass, leaving unknown or malformed ones as they are.
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let entity = rest[1..].find(';').filter(|&end| end <= 10).and_then(|end| {
+            let c = match &rest[1..=end] {
+                "nbsp" => ' ',
+                "lt" => '<',
+                "gt" => '>',
+                "quot" => '"',
+                "amp" => '&',
+                name => {
+                    let code = match name.strip_prefix("#x").or_else(|| name.strip_prefix("#X")) {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                        None => name.strip_prefix('#').and_then(|dec| dec.parse().ok()),
+                    };
+                    char::from_u32(code?)?
+                }
+            };
+            Some((c, end + 2))
+        });
+        match entity {
+            Some((c, len)) => {
+                decoded.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+/// Sort mods by [`SimpleMod::engagement_ratio`], highest first.
+pub fn sort_by_engagement(mods: &mut [SimpleMod]) {
+    mods.sort_by(|a, b| b.engagement_ratio().total_cmp(&a.engagement_ratio()));
+}
+
+/// A `SimpleMod` field to sort by with [`sort_mods_by_keys`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum SortMetric {
+    Downloads,
+    Follows,
+    TrendingPoints,
+    Comments,
+    /// Case-insensitive
+    Name,
+    /// Case-insensitive
+    Author,
+    Side,
+    ModType,
+    /// By the parsed timestamp. Dates that don't parse come after all others and are compared as text
+    LastReleased,
+}
+
+/// Direction for a [`SortKey`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+/// One level of a multi-key sort: a metric and a direction.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct SortKey {
+    pub metric: SortMetric,
+    pub direction: SortDirection,
+}
+
+impl SortKey {
+    /// Sort by `metric`, smallest first.
+    pub fn ascending(metric: SortMetric) -> Self {
+        Self { metric, direction: SortDirection::Ascending }
+    }
+
+    /// Sort by `metric`, largest first.
+    pub fn descending(metric: SortMetric) -> Self {
+        Self { metric, direction: SortDirection::Descending }
+    }
+
+    fn compare(&self, a: &SimpleMod, b: &SimpleMod) -> std::cmp::Ordering {
+        let ordering = match self.metric {
+            SortMetric::Downloads => a.downloads.cmp(&b.downloads),
+            SortMetric::Follows => a.follows.cmp(&b.follows),
+            SortMetric::TrendingPoints => a.trending_points.cmp(&b.trending_points),
+            SortMetric::Comments => a.comments.cmp(&b.comments),
+            SortMetric::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+            SortMetric::Author => a.author.to_lowercase().cmp(&b.author.to_lowercase()),
+            SortMetric::Side => a.side.cmp(&b.side),
+            SortMetric::ModType => a.mod_type.cmp(&b.mod_type),
+            SortMetric::LastReleased => match (parse_timestamp_fields(&a.last_released), parse_timestamp_fields(&b.last_released)) {
+                (Some(a), Some(b)) => a.cmp(&b),
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (None, None) => a.last_released.cmp(&b.last_released),
+            },
+        };
+        match self.direction {
+            SortDirection::Ascending => ordering,
+            SortDirection::Descending => ordering.reverse(),
+        }
+    }
+}
+
+/// Stable multi-level sort: mods are ordered by the first key, ties broken by the next, and so on.
+pub fn sort_mods_by_keys(mods: &mut [SimpleMod], keys: &[SortKey]) {
+    mods.sort_by(|a, b| {
+        keys.iter().fold(std::cmp::Ordering::Equal, |ordering, key| ordering.then_with(|| key.compare(a, b)))
+    });
+}
+
+/// Downloads per day between two snapshots of the same mod taken `elapsed` apart.
+///
+/// Returns 0.0 when `elapsed` is zero. The rate is negative if the download count went down.
+pub fn download_rate(old: &SimpleMod, new: &SimpleMod, elapsed: Duration) -> f64 {
+    let days = elapsed.as_secs_f64() / 86_400.0;
+    if days <= 0.0 {
+        return 0.0;
+    }
+    (new.downloads as f64 - old.downloads as f64) / days
+}
+
+/// Differences between two snapshots of the mods list, see [`diff_mods`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ModsDiff {
+    /// Mods only in the new snapshot
+    pub added: Vec<SimpleMod>,
+    /// Mods only in the old snapshot
+    pub removed: Vec<SimpleMod>,
+    /// `(mod_id, old url_alias, new url_alias)` for mods in both whose alias changed
+    pub alias_changes: Vec<(u32, Option<String>, Option<String>)>,
+}
+
+/// Compare two snapshots of the mods list, matching mods by `mod_id`.
+///
+/// Added mods and alias changes are in `new` order, removed mods in `old` order.
+pub fn diff_mods(old: &[SimpleMod], new: &[SimpleMod]) -> ModsDiff {
+    let old_by_id: HashMap<u32, &SimpleMod> = old.iter().map(|m| (m.mod_id, m)).collect();
+    let new_ids: HashSet<u32> = new.iter().map(|m| m.mod_id).collect();
+
+    let mut diff = ModsDiff::default();
+    for m in new {
+        match old_by_id.get(&m.mod_id) {
+            None => diff.added.push(m.clone()),
+            Some(previous) if previous.url_alias != m.url_alias => {
+                diff.alias_changes.push((m.mod_id, previous.url_alias.clone(), m.url_alias.clone()))
+            }
+            Some(_) => {}
+        }
+    }
+    diff.removed = old.iter().filter(|m| !new_ids.contains(&m.mod_id)).cloned().collect();
+    diff
+}
+
+/// Totals across a mods list, see [`CatalogStats::from_mods`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CatalogStats {
+    pub total_mods: usize,
+    pub total_downloads: u64,
+    pub total_follows: u64,
+    pub total_comments: u64,
+    /// Mean downloads per mod, 0.0 for an empty list
+    pub average_downloads: f64,
+}
+
+impl CatalogStats {
+    /// Sum up `mods`.
+    pub fn from_mods(mods: &[SimpleMod]) -> Self {
+        let mut stats = mods.iter().fold(CatalogStats::default(), |mut stats, m| {
+            stats.total_downloads += m.downloads as u64;
+            stats.total_follows += m.follows as u64;
+            stats.total_comments += m.comments as u64;
+            stats
+        });
+        stats.total_mods = mods.len();
+        if !mods.is_empty() {
+            stats.average_downloads = stats.total_downloads as f64 / mods.len() as f64;
+        }
+        stats
+    }
 }
 
 impl From<SimpleMod> for DetailedMod {
@@ -111,54 +405,205 @@ impl From<SimpleMod> for DetailedMod {
             tags: simple.tags,
             releases: vec![],
             screenshots: vec![],
+            is_stub: true,
+            extra: simple.extra,
         }
     }
 }
 
+/// Origin of the mod DB website, which serves release files and mod pages.
+const SITE_URL: &str = "https://mods.vintagestory.at";
+
+/// Host of the CDN serving logos and screenshots.
+pub(crate) const ASSET_HOST: &str = "https://moddbcdn.vintagestory.at";
+
+/// File name of the placeholder logo the site shows for mods without one.
+const DEFAULT_LOGO_FILENAME: &str = "mod-default.png";
+
+/// Split a `YYYY-MM-DD HH:MM:SS` timestamp into its numbers, which order chronologically.
+fn parse_timestamp_fields(value: &str) -> Option<[u32; 6]> {
+    let mut parts = value.trim().split(['-', ' ', ':']);
+    let mut fields = [0; 6];
+    for field in &mut fields {
+        let part = parts.next()?;
+        if part.is_empty() || !part.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        *field = part.parse().ok()?;
+    }
+    parts.next().is_none().then_some(fields)
+}
+
+/// Resolve `path` against `base`, leaving absolute URLs untouched.
+pub(crate) fn resolve_url(base: &str, path: &str) -> String {
+    if path.starts_with("http://") || path.starts_with("https://") {
+        path.to_string()
+    } else {
+        format!("{}/{}", base.trim_end_matches('/'), path.trim_start_matches('/'))
+    }
+}
+
+/// Whether `logo` names an actual logo rather than being blank or the site's placeholder.
+fn is_custom_logo(logo: &str) -> bool {
+    let logo = logo.trim();
+    !logo.is_empty() && !logo.rsplit('/').next().unwrap_or(logo).eq_ignore_ascii_case(DEFAULT_LOGO_FILENAME)
+}
+
 /// Full detailed mod returned by `/mod/{id}`
-#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct DetailedMod {
-    #[serde(rename(deserialize = "modid"))]
+    #[serde(rename = "modid")]
     pub mod_id: u32,
-    #[serde(rename(deserialize = "assetid"))]
+    #[serde(rename = "assetid")]
     pub asset_id: u32,
     pub name: String,
     pub text: String,
     pub author: String,
-    #[serde(rename(deserialize = "urlalias"))]
+    #[serde(rename = "urlalias")]
     pub url_alias: Option<String>,
-    #[serde(rename(deserialize = "logofilename"))]
+    #[serde(rename = "logofilename")]
     pub logo_filename: Option<String>,
-    #[serde(rename(deserialize = "logofile"))]
+    #[serde(rename = "logofile")]
     pub logo_file: Option<String>,
-    #[serde(rename(deserialize = "logofiledb"))]
+    #[serde(rename = "logofiledb")]
     pub logo_file_db: Option<String>,
-    #[serde(rename(deserialize = "homepageurl"))]
+    #[serde(rename = "homepageurl")]
     pub homepage_url: Option<String>,
-    #[serde(rename(deserialize = "sourcecodeurl"))]
+    #[serde(rename = "sourcecodeurl")]
     pub source_code_url: Option<String>,
-    #[serde(rename(deserialize = "trailervideourl"))]
+    #[serde(rename = "trailervideourl")]
     pub trailer_video_url: Option<String>,
-    #[serde(rename(deserialize = "issuetrackerurl"))]
+    #[serde(rename = "issuetrackerurl")]
     pub issue_tracker_url: Option<String>,
-    #[serde(rename(deserialize = "wikiurl"))]
+    #[serde(rename = "wikiurl")]
     pub wiki_url: Option<String>,
     pub downloads: u32,
     pub follows: u32,
-    #[serde(rename(deserialize = "trendingpoints"))]
+    #[serde(rename = "trendingpoints")]
     pub trending_points: u32,
     pub comments: u32,
     pub side: String,
-    #[serde(rename(deserialize = "type"))]
+    #[serde(rename = "type")]
     pub mod_type: String,
     pub created: String,
-    #[serde(rename(deserialize = "lastreleased"))]
+    #[serde(rename = "lastreleased")]
     pub last_released: String,
-    #[serde(rename(deserialize = "lastmodified"))]
+    #[serde(rename = "lastmodified")]
     pub last_modified: String,
     pub tags: Vec<String>,
     pub releases: Vec<DetailedModRelease>,
     pub screenshots: Vec<DetailedModScreenshot>,
+    /// True if this was built from a `SimpleMod` rather than fetched from `/mod/{id}`,
+    /// meaning releases, screenshots, links and dates are missing. Never sent by the API, and only serialized
+    /// when set so stubs round-trip while fetched mods keep the API's shape.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_stub: bool,
+    /// Fields sent by the API that this struct doesn't know about yet, kept so they survive re-serialization.
+    #[serde(flatten, skip_serializing_if = "serde_json::Map::is_empty")]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl DetailedMod {
+    /// The typed `side` value.
+    pub fn side(&self) -> ModSide {
+        ModSide::from(self.side.as_str())
+    }
+
+    /// The typed `mod_type` value.
+    pub fn mod_type(&self) -> ModType {
+        ModType::from(self.mod_type.as_str())
+    }
+
+    /// Releases sorted by `created`, newest first.
+    ///
+    /// Dates are compared as parsed `YYYY-MM-DD HH:MM:SS` timestamps. Releases with the same date, or one that
+    /// doesn't parse, are ordered by `release_id`, newest first, and releases with unparseable dates go last.
+    pub fn releases_sorted(&self) -> Vec<&DetailedModRelease> {
+        let mut releases: Vec<_> = self.releases.iter().collect();
+        releases.sort_by_key(|r| Reverse(r.created_key()));
+        releases
+    }
+
+    /// The newest `n` releases, newest first.
+    pub fn recent_releases(&self, n: usize) -> Vec<&DetailedModRelease> {
+        let mut releases = self.releases_sorted();
+        releases.truncate(n);
+        releases
+    }
+
+    /// Releases with more than `min_downloads` downloads, most downloaded first.
+    pub fn popular_releases(&self, min_downloads: u32) -> Vec<&DetailedModRelease> {
+        let mut releases: Vec<_> = self.releases.iter().filter(|r| r.downloads > min_downloads).collect();
+        releases.sort_by_key(|r| Reverse(r.downloads));
+        releases
+    }
+
+    /// Whether the mod has its own logo rather than none or the site's default placeholder.
+    pub fn has_custom_logo(&self) -> bool {
+        self.logo_path().is_some() || self.logo_filename.as_deref().is_some_and(is_custom_logo)
+    }
+
+    /// Absolute URL of the mod's logo on the mod DB CDN, or `None` if it only has the site's placeholder.
+    ///
+    /// `logo_file` is preferred, then `logo_file_db`, skipping blank and placeholder values. `logo_filename` is
+    /// never used since it only holds a bare file name, not a path on the CDN.
+    /// Use [`VintageStoryModDbApi::logo_url`](crate::VintageStoryModDbApi::logo_url) for a custom asset host.
+    pub fn logo_url(&self) -> Option<String> {
+        self.logo_url_on(ASSET_HOST)
+    }
+
+    pub(crate) fn logo_url_on(&self, host: &str) -> Option<String> {
+        self.logo_path().map(|logo| resolve_url(host, logo))
+    }
+
+    /// The first logo field holding a path or URL of an actual logo.
+    fn logo_path(&self) -> Option<&str> {
+        [&self.logo_file, &self.logo_file_db].into_iter()
+            .flatten()
+            .map(|logo| logo.trim())
+            .find(|logo| is_custom_logo(logo))
+    }
+
+    /// Whether any release is tagged with `game_version` (e.g. `"v1.20.3"`).
+    pub fn supports_version(&self, game_version: &str) -> bool {
+        self.compatible_releases(game_version).next().is_some()
+    }
+
+    /// Every game version any release is tagged with, oldest first and without duplicates.
+    pub fn supported_versions(&self) -> Vec<ParsedGameVersion> {
+        let mut versions: Vec<_> = self.releases.iter().flat_map(|r| r.parsed_tags().game_versions).collect();
+        versions.sort();
+        versions.dedup();
+        versions
+    }
+
+    /// Every release tagged with `game_version` (e.g. `"v1.20.3"`), newest first.
+    pub fn releases_for(&self, game_version: &str) -> Vec<&DetailedModRelease> {
+        let mut releases: Vec<_> = self.compatible_releases(game_version).collect();
+        releases.sort_by_key(|r| Reverse(r.created_key()));
+        releases
+    }
+
+    /// The newest release tagged with `game_version` (e.g. `"v1.20.3"`), if any.
+    pub fn best_release_for(&self, game_version: &str) -> Option<&DetailedModRelease> {
+        self.compatible_releases(game_version).max_by_key(|r| r.created_key())
+    }
+
+    /// The newest stable release tagged with `game_version`, skipping alpha/beta/rc/dev releases.
+    pub fn latest_stable_release(&self, game_version: &str) -> Option<&DetailedModRelease> {
+        self.compatible_releases(game_version)
+            .filter(|r| r.channel().is_stable())
+            .max_by_key(|r| r.created_key())
+    }
+
+    /// Releases tagged with `game_version`, compared as parsed versions when it parses as one.
+    fn compatible_releases<'a>(&'a self, game_version: &str) -> impl Iterator<Item = &'a DetailedModRelease> {
+        let parsed = ParsedGameVersion::parse(game_version);
+        self.releases.iter().filter(move |r| match &parsed {
+            Some(parsed) => r.parsed_tags().game_versions.contains(parsed),
+            None => r.tags.iter().any(|t| t == game_version),
+        })
+    }
 }
 
 impl From<DetailedMod> for SimpleMod {
@@ -180,35 +625,251 @@ impl From<DetailedMod> for SimpleMod {
             logo: detail.logo_file,
             tags: detail.tags,
             last_released: detail.last_released,
+            extra: detail.extra,
         }
     }
 }
 
 /// Mod release info (only in DetailedMod)
-#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct DetailedModRelease {
-    #[serde(rename(deserialize = "releaseid"))]
+    #[serde(rename = "releaseid")]
     pub release_id: u32,
-    #[serde(rename(deserialize = "mainfile"))]
+    #[serde(rename = "mainfile")]
     pub main_file: String,
     #[serde(deserialize_with = "string_or_null")]
     pub filename: Option<String>,
-    #[serde(rename(deserialize = "fileid"))]
+    #[serde(rename = "fileid")]
     pub file_id: Option<u32>,
     pub downloads: u32,
     pub tags: Vec<String>,
-    #[serde(rename(deserialize = "modidstr"))]
+    #[serde(rename = "modidstr")]
     pub mod_id_str: Option<String>,
-    #[serde(rename(deserialize = "modversion"))]
+    #[serde(rename = "modversion")]
     pub mod_version: String,
     pub created: String,
     pub changelog: Option<String>,
 }
 
 impl DetailedModRelease {
+    /// Key ordering releases by their parsed `created` timestamp, then by `release_id`.
+    ///
+    /// Unparseable dates have no timestamp, so they order before every parsed one.
+    fn created_key(&self) -> (Option<[u32; 6]>, u32) {
+        (parse_timestamp_fields(&self.created), self.release_id)
+    }
+
     pub fn get_filename(&self) -> String {
         self.filename.clone().unwrap_or(self.mod_id_str.clone().map(|str| format!("{}.zip", str)).unwrap_or(self.main_file.clone()))
     }
+
+    /// The changelog as plain text, see [`strip_html`]. `None` if there's no changelog or it's blank.
+    pub fn changelog_plain(&self) -> Option<String> {
+        self.changelog.as_deref().map(strip_html).filter(|text| !text.is_empty())
+    }
+
+    /// Absolute URL of the release's main file, resolving site-relative paths.
+    pub fn download_link(&self) -> String {
+        resolve_url(SITE_URL, &self.main_file)
+    }
+
+    /// URL of the files tab on the mod's page, given its `url_alias` (or `show/mod/{asset_id}`).
+    ///
+    /// The site has no page per release, so this is the closest link that lists it.
+    pub fn release_page_url(&self, mod_alias: &str) -> String {
+        format!("{}/{}#tab-files", SITE_URL, mod_alias.trim_matches('/'))
+    }
+
+    /// The release channel, detected from markers like `-beta` or `-rc.1` in `mod_version`.
+    pub fn channel(&self) -> ReleaseChannel {
+        ReleaseChannel::from_version(&self.mod_version)
+    }
+
+    /// The release tags split into parsed game versions and any other markers.
+    pub fn parsed_tags(&self) -> ReleaseTags {
+        let mut tags = ReleaseTags::default();
+        for tag in &self.tags {
+            match ParsedGameVersion::parse(tag) {
+                Some(version) => tags.game_versions.push(version),
+                None => tags.other.push(tag.clone()),
+            }
+        }
+        tags
+    }
+
+    /// The release tags that are game versions (e.g. `"v1.20.3"`), ignoring any other markers.
+    pub fn game_version_tags(&self) -> Vec<&str> {
+        self.tags.iter().map(String::as_str).filter(|t| ParsedGameVersion::parse(t).is_some()).collect()
+    }
+}
+
+/// A release's tags, split by [`DetailedModRelease::parsed_tags`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReleaseTags {
+    /// Tags that parse as game versions, in their original order
+    pub game_versions: Vec<ParsedGameVersion>,
+    /// Every other tag
+    pub other: Vec<String>,
+}
+
+/// Where a mod has to be installed, parsed from the `side` field.
+///
+/// The models keep `side` as the raw string so values the site adds later survive a round trip,
+/// the typed view is available through `SimpleMod::side()` and `DetailedMod::side()`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ModSide {
+    Client,
+    Server,
+    Both,
+    /// Any other value, kept as sent
+    Unknown(String),
+}
+
+impl ModSide {
+    /// The value as the API spells it.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ModSide::Client => "client",
+            ModSide::Server => "server",
+            ModSide::Both => "both",
+            ModSide::Unknown(side) => side,
+        }
+    }
+}
+
+impl From<&str> for ModSide {
+    /// Parse a side ignoring case and surrounding whitespace.
+    fn from(side: &str) -> Self {
+        match side.trim().to_ascii_lowercase().as_str() {
+            "client" => ModSide::Client,
+            "server" => ModSide::Server,
+            "both" => ModSide::Both,
+            _ => ModSide::Unknown(side.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for ModSide {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for ModSide {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ModSide {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(ModSide::from(String::deserialize(deserializer)?.as_str()))
+    }
+}
+
+/// Kind of mod DB entry, parsed from the `type` field.
+///
+/// Like [`ModSide`], the raw `mod_type` string is kept on the models and this is the typed view.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ModType {
+    /// A game mod
+    Mod,
+    /// A standalone tool, like a launcher or map viewer
+    ExternalTool,
+    /// A menu theme
+    Theme,
+    /// Any other value, kept as sent
+    Other(String),
+}
+
+impl ModType {
+    /// The value as the API spells it.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ModType::Mod => "mod",
+            ModType::ExternalTool => "externaltool",
+            ModType::Theme => "theme",
+            ModType::Other(mod_type) => mod_type,
+        }
+    }
+}
+
+impl From<&str> for ModType {
+    /// Parse a type ignoring case and surrounding whitespace.
+    fn from(mod_type: &str) -> Self {
+        match mod_type.trim().to_ascii_lowercase().as_str() {
+            "mod" => ModType::Mod,
+            "externaltool" => ModType::ExternalTool,
+            "theme" => ModType::Theme,
+            _ => ModType::Other(mod_type.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for ModType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for ModType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ModType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(ModType::from(String::deserialize(deserializer)?.as_str()))
+    }
+}
+
+/// Release channel of a mod release, as detected from its version string.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ReleaseChannel {
+    Stable,
+    Alpha,
+    Beta,
+    /// Versions marked `pre`
+    Prerelease,
+    ReleaseCandidate,
+    Dev,
+}
+
+impl ReleaseChannel {
+    /// Detect the channel from a version like `1.2.0`, `1.2.0-beta.1` or `1.2.0rc2`.
+    ///
+    /// Only the part after the first character that isn't a digit or dot is inspected, split into words at
+    /// anything that isn't a letter. Whole words are matched (`dev`, `rc`, `beta`, `alpha`, `pre`, `preview`,
+    /// `prerelease`), so a suffix like `-source` stays stable. Versions without a recognised marker are stable.
+    pub fn from_version(version: &str) -> Self {
+        let version = version.trim().trim_start_matches(['v', 'V']);
+        let suffix = version
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .map(|i| version[i..].to_ascii_lowercase())
+            .unwrap_or_default();
+        let labels: Vec<&str> = suffix.split(|c: char| !c.is_ascii_alphabetic()).filter(|l| !l.is_empty()).collect();
+        let has = |names: &[&str]| labels.iter().any(|l| names.contains(l));
+
+        if has(&["dev"]) {
+            ReleaseChannel::Dev
+        } else if has(&["rc"]) {
+            ReleaseChannel::ReleaseCandidate
+        } else if has(&["beta"]) {
+            ReleaseChannel::Beta
+        } else if has(&["alpha"]) {
+            ReleaseChannel::Alpha
+        } else if has(&["pre", "preview", "prerelease"]) {
+            ReleaseChannel::Prerelease
+        } else {
+            ReleaseChannel::Stable
+        }
+    }
+
+    /// Whether this is the stable channel.
+    pub fn is_stable(&self) -> bool {
+        *self == ReleaseChannel::Stable
+    }
 }
 
 fn string_or_null<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
@@ -272,55 +933,484 @@ where
 
 
 /// Screenshot entry for a mod
-#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct DetailedModScreenshot {
-    #[serde(rename(deserialize = "fileid"))]
+    #[serde(rename = "fileid")]
     pub file_id: u32,
-    #[serde(rename(deserialize = "mainfile"))]
+    #[serde(rename = "mainfile")]
     pub main_file: String,
     pub filename: String,
-    #[serde(rename(deserialize = "thumbnailfilename"))]
+    #[serde(rename = "thumbnailfilename")]
     pub thumbnail_filename: String,
     pub created: String,
 }
 
+impl DetailedModScreenshot {
+    /// Absolute URL of the full-size image on the mod DB CDN.
+    ///
+    /// Use [`VintageStoryModDbApi::screenshot_url`](crate::VintageStoryModDbApi::screenshot_url) for a custom asset host.
+    pub fn image_url(&self) -> String {
+        self.image_url_on(ASSET_HOST)
+    }
+
+    /// Absolute URL of the thumbnail on the mod DB CDN.
+    ///
+    /// A bare `thumbnail_filename` sits next to the full-size image, so it's resolved against `main_file`'s folder.
+    pub fn thumbnail_url(&self) -> String {
+        self.thumbnail_url_on(ASSET_HOST)
+    }
+
+    pub(crate) fn image_url_on(&self, host: &str) -> String {
+        resolve_url(host, &self.main_file)
+    }
+
+    pub(crate) fn thumbnail_url_on(&self, host: &str) -> String {
+        let thumbnail = self.thumbnail_filename.trim();
+        if thumbnail.contains('/') {
+            return resolve_url(host, thumbnail);
+        }
+        let image = self.image_url_on(host);
+        let folder = image.rsplit_once('/').map_or(host, |(folder, _)| folder);
+        format!("{}/{}", folder, thumbnail)
+    }
+}
+
 /// Tag object returned by `/tags`
-#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct Tag {
-    #[serde(rename(deserialize = "tagid"))]
+    #[serde(rename = "tagid")]
     pub tag_id: u32,
     pub name: String,
     pub color: String,
 }
 
 /// Author object returned by `/authors`
-#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+///
+/// This is all the author data the API offers: there is no per-author profile endpoint, so avatars and bios
+/// are not available. Other author information only appears on mods (`author` name) and comments (`user_id`).
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct Author {
-    #[serde(rename(deserialize = "userid"))]
+    #[serde(rename = "userid")]
     pub userid: u32,
     pub name: Option<String>,
 }
 
+impl Author {
+    /// Placeholder [`Self::display_name`] uses for authors without a name.
+    pub const UNNAMED: &'static str = "<Unnamed>";
+
+    /// The name for display, or [`Self::UNNAMED`] if it's missing or blank.
+    pub fn display_name(&self) -> &str {
+        self.display_name_or(Self::UNNAMED)
+    }
+
+    /// The name for display, or `fallback` if it's missing or blank.
+    pub fn display_name_or<'a>(&'a self, fallback: &'a str) -> &'a str {
+        self.name.as_deref().map(str::trim).filter(|name| !name.is_empty()).unwrap_or(fallback)
+    }
+}
+
 /// GameVersion object returned by `/gameversions`
-#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct GameVersion {
-    #[serde(rename(deserialize = "tagid"))]
+    #[serde(rename = "tagid")]
     pub tag_id: i64,
     pub name: String,
     pub color: String,
 }
 
+impl GameVersion {
+    /// The version name parsed for comparison, or `None` if it isn't a version.
+    pub fn parsed(&self) -> Option<ParsedGameVersion> {
+        ParsedGameVersion::parse(&self.name)
+    }
+
+    /// Whether this version is newer than `other`, comparing parsed versions (so `v1.20.0` is newer than `v1.20.0-rc.1`).
+    ///
+    /// False if either name isn't a version.
+    pub fn is_newer_than(&self, other: &GameVersion) -> bool {
+        matches!((self.parsed(), other.parsed()), (Some(a), Some(b)) if a > b)
+    }
+}
+
+/// A game version such as `v1.20.3` or `v1.21.0-rc.2`, parsed so versions can be compared.
+///
+/// Stable versions order after their prereleases, and prereleases order `dev` < `pre` < `rc`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParsedGameVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+    /// Prerelease label such as `pre.1`, `rc.2` or `dev.3`, `None` for stable versions
+    pub prerelease: Option<String>,
+}
+
+impl ParsedGameVersion {
+    /// Parse a version like `v1.20.3`, `1.20` or `v1.21.0-pre.1`. Returns `None` if it isn't a version.
+    pub fn parse(version: &str) -> Option<Self> {
+        let version = version.trim();
+        let version = version.strip_prefix(['v', 'V']).unwrap_or(version);
+        let (core, prerelease) = match version.split_once('-') {
+            Some((core, pre)) if !pre.is_empty() => (core, Some(pre.to_string())),
+            Some(_) => return None,
+            None => (version, None),
+        };
+
+        let mut parts = core.split('.').map(|p| p.parse::<u32>().ok());
+        let major = parts.next()??;
+        let minor = parts.next()??;
+        let patch = parts.next().unwrap_or(Some(0))?;
+        if parts.next().is_some() {
+            return None;
+        }
+
+        Some(Self { major, minor, patch, prerelease })
+    }
+
+    /// Whether this is a stable (non `pre`/`rc`/`dev`) version.
+    pub fn is_stable(&self) -> bool {
+        self.prerelease.is_none()
+    }
+
+    fn prerelease_key(&self) -> Option<(u8, u32, &str)> {
+        self.prerelease.as_deref().map(|pre| {
+            let (label, number) = pre.split_once('.').unwrap_or((pre, ""));
+            let rank = match label.to_ascii_lowercase().as_str() {
+                "dev" => 0,
+                "pre" => 1,
+                "rc" => 2,
+                _ => 3,
+            };
+            (rank, number.parse().unwrap_or(0), pre)
+        })
+    }
+}
+
+impl Ord for ParsedGameVersion {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.major, self.minor, self.patch)
+            .cmp(&(other.major, other.minor, other.patch))
+            .then_with(|| match (self.prerelease_key(), other.prerelease_key()) {
+                (None, None) => std::cmp::Ordering::Equal,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (Some(a), Some(b)) => a.cmp(&b),
+            })
+    }
+}
+
+impl PartialOrd for ParsedGameVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for ParsedGameVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "v{}.{}.{}", self.major, self.minor, self.patch)?;
+        if let Some(pre) = &self.prerelease {
+            write!(f, "-{}", pre)?;
+        }
+        Ok(())
+    }
+}
+
+/// Catalog data saved by [`VintageStoryModDbApi::export_snapshot`](crate::VintageStoryModDbApi::export_snapshot),
+/// for working offline or reproducibly.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct CatalogSnapshot {
+    /// Format version, see [`Self::VERSION`]
+    pub version: u32,
+    pub mods: Vec<SimpleMod>,
+    pub authors: Vec<Author>,
+    #[serde(default)]
+    pub tags: Vec<Tag>,
+    #[serde(default)]
+    pub game_versions: Vec<GameVersion>,
+}
+
+impl CatalogSnapshot {
+    /// Format version written by this crate. Snapshots with another version are rejected on import.
+    pub const VERSION: u32 = 1;
+}
+
+// Optional feature: semver parsing of mod versions
+#[cfg(feature = "semver")]
+mod semver_support {
+    use super::*;
+    use crate::error::ApiError;
+    use semver::{Version, VersionReq};
+
+    impl DetailedMod {
+        /// Releases whose `mod_version` satisfies a semver requirement like `">=1.2, <2.0"`, highest version first.
+        ///
+        /// Versions that can't be parsed even leniently (see [`parse_version_lenient`]) are excluded.
+        /// Returns `ApiError::Unexpected` if the requirement itself is invalid.
+        pub fn releases_matching(&self, req: &str) -> Result<Vec<&DetailedModRelease>, ApiError> {
+            let req = VersionReq::parse(req).map_err(|e| ApiError::Unexpected(format!("Invalid version requirement {}: {}", req, e)))?;
+            let mut releases: Vec<_> = self.releases.iter()
+                .filter_map(|r| parse_version_lenient(&r.mod_version).map(|v| (v, r)))
+                .filter(|(v, _)| req.matches(v))
+                .collect();
+            releases.sort_by(|a, b| b.0.cmp(&a.0));
+            Ok(releases.into_iter().map(|(_, r)| r).collect())
+        }
+
+        /// The release with the highest `mod_version`, ignoring versions that can't be parsed leniently.
+        ///
+        /// Unlike [`Self::releases_sorted`] this doesn't depend on upload order, so it finds the real latest version.
+        pub fn latest_release_by_version(&self) -> Option<&DetailedModRelease> {
+            self.releases.iter()
+                .filter_map(|r| r.semver().ok().map(|v| (v, r)))
+                .max_by(|a, b| a.0.cmp(&b.0))
+                .map(|(_, r)| r)
+        }
+    }
+
+    impl GameVersion {
+        /// The version name as semver, with `pre`/`rc`/`dev` markers as the prerelease (`v1.21.0-rc.2` becomes `1.21.0-rc.2`).
+        ///
+        /// Returns `None` if the name isn't a version.
+        pub fn parse_version(&self) -> Option<Version> {
+            let parsed = self.parsed()?;
+            let mut version = Version::new(parsed.major as u64, parsed.minor as u64, parsed.patch as u64);
+            if let Some(pre) = &parsed.prerelease {
+                version.pre = semver::Prerelease::new(pre).ok()?;
+            }
+            Some(version)
+        }
+    }
+
+    impl DetailedModRelease {
+        /// `mod_version` as semver, parsed leniently (see [`parse_version_lenient`]).
+        ///
+        /// Returns `ApiError::Unexpected` for versions that aren't semver even after normalization.
+        pub fn semver(&self) -> Result<Version, ApiError> {
+            parse_version_lenient(&self.mod_version)
+                .ok_or_else(|| ApiError::Unexpected(format!("Release {} has non-semver version {:?}", self.release_id, self.mod_version)))
+        }
+    }
+
+    /// Parse a version as semver, accepting a leading `v` and missing minor/patch numbers (`1.0` becomes `1.0.0`).
+    pub fn parse_version_lenient(version: &str) -> Option<Version> {
+        let version = version.trim();
+        let version = version.strip_prefix(['v', 'V']).unwrap_or(version);
+        let split = version.find(['-', '+']).unwrap_or(version.len());
+        let (core, rest) = version.split_at(split);
+
+        let parts: Vec<&str> = core.split('.').collect();
+        if parts.is_empty() || parts.len() > 3 || parts.iter().any(|p| p.is_empty() || !p.bytes().all(|b| b.is_ascii_digit())) {
+            return None;
+        }
+        let padded = parts.iter().copied().chain(["0", "0"]).take(3).collect::<Vec<_>>().join(".");
+        Version::parse(&format!("{}{}", padded, rest)).ok()
+    }
+}
+
+#[cfg(feature = "semver")]
+pub use semver_support::parse_version_lenient;
+
+// Optional feature: typed timestamps using `chrono`
+#[cfg(feature = "chrono")]
+mod chrono_support {
+    use crate::error::ApiError;
+    use chrono::{DateTime, Datelike, Days, NaiveDate, NaiveDateTime, Utc};
+
+    /// Format the API uses for all timestamps, e.g. `2024-05-01 13:37:00`.
+    const TIMESTAMP_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+    /// Parse an API timestamp such as `2024-05-01 13:37:00`, interpreted as UTC.
+    fn parse_timestamp(value: &str) -> Result<DateTime<Utc>, ApiError> {
+        NaiveDateTime::parse_from_str(value.trim(), TIMESTAMP_FORMAT)
+            .map(|dt| dt.and_utc())
+            .map_err(|e| ApiError::Unexpected(format!("Invalid timestamp {:?}: {}", value, e)))
+    }
+
+    impl super::SimpleMod {
+        /// `last_released` as a UTC timestamp.
+        pub fn last_released_at(&self) -> Result<DateTime<Utc>, ApiError> {
+            parse_timestamp(&self.last_released)
+        }
+    }
+
+    impl super::DetailedMod {
+        /// `created` as a UTC timestamp.
+        ///
+        /// Stubs built from a [`SimpleMod`](super::SimpleMod) have a placeholder instead of a date, so this
+        /// returns `ApiError::Unexpected` for them, like for any other unparseable value.
+        pub fn created_at(&self) -> Result<DateTime<Utc>, ApiError> {
+            parse_timestamp(&self.created)
+        }
+
+        /// `last_released` as a UTC timestamp.
+        pub fn last_released_at(&self) -> Result<DateTime<Utc>, ApiError> {
+            parse_timestamp(&self.last_released)
+        }
+
+        /// `last_modified` as a UTC timestamp. Errors for stubs, see [`Self::created_at`].
+        pub fn last_modified_at(&self) -> Result<DateTime<Utc>, ApiError> {
+            parse_timestamp(&self.last_modified)
+        }
+
+        /// When the mod was first published.
+        ///
+        /// Uses the top-level `created` date when it's a real timestamp, otherwise (e.g. for stubs built
+        /// from a [`SimpleMod`](super::SimpleMod)) falls back to the oldest release's `created` date.
+        pub fn first_published(&self) -> Option<DateTime<Utc>> {
+            if !self.is_stub && let Ok(created) = self.created_at() {
+                return Some(created);
+            }
+            self.releases.iter().filter_map(|r| r.created_at().ok()).min()
+        }
+    }
+
+    impl super::DetailedModRelease {
+        /// `created` as a UTC timestamp.
+        pub fn created_at(&self) -> Result<DateTime<Utc>, ApiError> {
+            parse_timestamp(&self.created)
+        }
+    }
+
+    impl super::Comment {
+        /// `created` as a UTC timestamp.
+        pub fn created_at(&self) -> Result<DateTime<Utc>, ApiError> {
+            parse_timestamp(&self.created)
+        }
+
+        /// `last_modified` as a UTC timestamp.
+        pub fn last_modified_at(&self) -> Result<DateTime<Utc>, ApiError> {
+            parse_timestamp(&self.last_modified)
+        }
+    }
+
+    /// Granularity for grouping timestamps into histogram buckets.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    pub enum Bucket {
+        /// Keyed by the date itself
+        Day,
+        /// Keyed by the Monday starting the week
+        Week,
+        /// Keyed by the first day of the month
+        Month,
+    }
+
+    impl Bucket {
+        /// The date identifying the bucket `date` falls into.
+        pub fn start_of(&self, date: NaiveDate) -> NaiveDate {
+            match self {
+                Bucket::Day => date,
+                Bucket::Week => date - Days::new(date.weekday().num_days_from_monday() as u64),
+                Bucket::Month => date.with_day(1).unwrap_or(date),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+pub use chrono_support::Bucket;
+
+/// A dependency declared in a mod's `modinfo.json`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct ModDependency {
+    /// The required mod's id string, e.g. `"game"` for the base game
+    pub mod_id: String,
+    /// The minimum version, empty or `"*"` for any
+    pub version: String,
+}
+
+impl ModDependency {
+    /// Parse the `dependencies` map of a `modinfo.json` file, sorted by mod id.
+    ///
+    /// Keys are matched ignoring case, as the game does. Like the game, `//` and `/* */` comments and trailing
+    /// commas are accepted. A file without dependencies gives an empty list.
+    pub fn parse_modinfo(json: &str) -> Result<Vec<ModDependency>, crate::error::ApiError> {
+        let info: serde_json::Value = serde_json::from_str(&strip_json_comments(json.trim_start_matches('\u{feff}')))?;
+        let dependencies = info.as_object()
+            .and_then(|info| info.iter().find(|(key, _)| key.eq_ignore_ascii_case("dependencies")))
+            .and_then(|(_, deps)| deps.as_object());
+        let Some(dependencies) = dependencies else {
+            return Ok(vec![]);
+        };
+
+        let mut parsed: Vec<_> = dependencies.iter()
+            .map(|(mod_id, version)| ModDependency {
+                mod_id: mod_id.clone(),
+                version: version.as_str().unwrap_or_default().to_string(),
+            })
+            .collect();
+        parsed.sort_by(|a, b| a.mod_id.cmp(&b.mod_id));
+        Ok(parsed)
+    }
+}
+
+/// Remove `//` and `/* */` comments and trailing commas before `}` or `]` from lenient JSON, leaving strings untouched.
+fn strip_json_comments(json: &str) -> String {
+    let mut stripped = String::with_capacity(json.len());
+    let mut chars = json.chars().peekable();
+    let mut in_string = false;
+    let mut trailing_comma = None;
+    while let Some(c) = chars.next() {
+        if in_string {
+            stripped.push(c);
+            match c {
+                '\\' => stripped.extend(chars.next()),
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match (c, chars.peek()) {
+            ('/', Some('/')) => {
+                while chars.next_if(|&c| c != '\n').is_some() {}
+            }
+            ('/', Some('*')) => {
+                chars.next();
+                let mut previous = ' ';
+                for c in chars.by_ref() {
+                    if previous == '*' && c == '/' {
+                        break;
+                    }
+                    previous = c;
+                }
+            }
+            _ if c.is_whitespace() => stripped.push(c),
+            _ => {
+                if let Some(comma) = trailing_comma.take()
+                    && matches!(c, '}' | ']')
+                {
+                    stripped.remove(comma);
+                }
+                if c == ',' {
+                    trailing_comma = Some(stripped.len());
+                }
+                in_string = c == '"';
+                stripped.push(c);
+            }
+        }
+    }
+    stripped
+}
+
 /// Comment object returned by `/comments/{assetid}`
-#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct Comment {
-    #[serde(rename(deserialize = "commentid"))]
+    #[serde(rename = "commentid")]
     pub comment_id: u32,
-    #[serde(rename(deserialize = "assetid"))]
+    #[serde(rename = "assetid")]
     pub asset_id: u32,
-    #[serde(rename(deserialize = "userid"))]
+    #[serde(rename = "userid")]
     pub user_id: u32,
     pub text: String,
     pub created: String,
-    #[serde(rename(deserialize = "lastmodified"))]
+    #[serde(rename = "lastmodified")]
     pub last_modified: String,
+}
+
+impl Comment {
+    /// Key ordering comments by their parsed `created` timestamp, then by `comment_id`.
+    ///
+    /// Unparseable dates have no timestamp, so they order before every parsed one.
+    pub(crate) fn created_key(&self) -> (Option<[u32; 6]>, u32) {
+        (parse_timestamp_fields(&self.created), self.comment_id)
+    }
 }
\ No newline at end of file
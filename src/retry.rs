@@ -0,0 +1,45 @@
+//! Retry configuration for transient request failures.
+
+use std::time::Duration;
+
+/// How often and how long to wait before retrying a request that failed transiently.
+///
+/// Timeouts, connection failures and 5xx or 429 responses are retried, waiting `base_delay * backoff_factor^attempt`
+/// between attempts, or the server's `Retry-After` when it sends one. The default policy never retries.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt, 0 disables retrying
+    pub max_retries: u32,
+    /// Wait before the first retry
+    pub base_delay: Duration,
+    /// Factor the wait grows by with every retry
+    pub backoff_factor: f64,
+}
+
+impl RetryPolicy {
+    /// Retry up to `max_retries` times, starting at 500ms and doubling the wait every time.
+    pub fn new(max_retries: u32) -> Self {
+        Self { max_retries, ..Default::default() }
+    }
+
+    /// The wait before retry number `attempt`, starting at 0.
+    ///
+    /// Saturates at `Duration::MAX` instead of overflowing for large attempts or factors.
+    pub fn delay(&self, attempt: u32) -> Duration {
+        if self.base_delay.is_zero() {
+            return Duration::ZERO;
+        }
+        let factor = self.backoff_factor.max(0.0).powi(attempt.min(i32::MAX as u32) as i32);
+        Duration::try_from_secs_f64(self.base_delay.as_secs_f64() * factor).unwrap_or(Duration::MAX)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 0,
+            base_delay: Duration::from_millis(500),
+            backoff_factor: 2.0,
+        }
+    }
+}
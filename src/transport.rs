@@ -0,0 +1,177 @@
+//! HTTP transport abstraction for the VintageStory API client.
+//!
+//! The client only needs to issue GET requests and read the status and body back, so any HTTP stack
+//! (or an IPC bridge in a sandbox) can be plugged in by implementing [`Transport`].
+//! The default [`ReqwestTransport`] is available with the `reqwest` feature (on by default).
+
+use std::future::Future;
+use std::time::Duration;
+use thiserror::Error;
+
+/// Sends HTTP requests on behalf of [`VintageStoryModDbApi`](crate::VintageStoryModDbApi).
+pub trait Transport: Send + Sync {
+    /// Send a GET request to `url`, returning the HTTP status code and response body.
+    ///
+    /// Non-success statuses should be returned as `Ok`, the client decides how to handle them.
+    fn get(&self, url: &str) -> impl Future<Output = Result<(u16, String), TransportError>> + Send;
+
+    /// Like [`Self::get`], but also returning response headers the client can use, such as `Retry-After`.
+    ///
+    /// Defaults to calling `get` without any headers.
+    fn get_response(&self, url: &str) -> impl Future<Output = Result<TransportResponse, TransportError>> + Send {
+        async move {
+            let (status, body) = self.get(url).await?;
+            Ok(TransportResponse { status, body, headers: vec![] })
+        }
+    }
+
+    /// Like [`Self::get_response`], also sending `headers` with the request, such as `If-None-Match`.
+    ///
+    /// Defaults to calling `get_response` without them, so conditional requests simply get full responses.
+    fn get_with_headers(&self, url: &str, headers: &[(&str, &str)]) -> impl Future<Output = Result<TransportResponse, TransportError>> + Send {
+        let _ = headers;
+        self.get_response(url)
+    }
+
+    /// Wait for `duration` between retries.
+    ///
+    /// Defaults to `tokio::time::sleep`, transports used outside of tokio should override it.
+    fn sleep(&self, duration: Duration) -> impl Future<Output = ()> + Send {
+        tokio::time::sleep(duration)
+    }
+}
+
+/// A response returned by [`Transport::get_response`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransportResponse {
+    /// HTTP status code
+    pub status: u16,
+    /// Response body
+    pub body: String,
+    /// Response headers as `(name, value)` pairs, may be empty if the transport doesn't report them
+    pub headers: Vec<(String, String)>,
+}
+
+impl TransportResponse {
+    /// The value of the first header named `name`, ignoring case.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter().find(|(n, _)| n.eq_ignore_ascii_case(name)).map(|(_, v)| v.as_str())
+    }
+
+    /// The `Retry-After` delay, if sent in seconds.
+    pub fn retry_after(&self) -> Option<Duration> {
+        self.header("retry-after").and_then(|v| v.trim().parse().ok()).map(Duration::from_secs)
+    }
+}
+
+/// Represents a failure to complete a request at the transport level.
+#[derive(Error, Debug)]
+pub enum TransportError {
+    /// An error from the built-in reqwest transport
+    #[cfg(feature = "reqwest")]
+    #[error(transparent)]
+    Reqwest(#[from] reqwest::Error),
+
+    /// The request timed out
+    #[error("Request timed out")]
+    Timeout,
+
+    /// The connection could not be established
+    #[error("Connection failed: {0}")]
+    Connect(String),
+
+    /// Any other transport failure
+    #[error("{0}")]
+    Other(String),
+}
+
+impl TransportError {
+    /// Whether the failure is likely temporary (timeouts and connection failures).
+    pub fn is_transient(&self) -> bool {
+        match self {
+            #[cfg(feature = "reqwest")]
+            TransportError::Reqwest(e) => e.is_timeout() || e.is_connect(),
+            TransportError::Timeout | TransportError::Connect(_) => true,
+            TransportError::Other(_) => false,
+        }
+    }
+}
+
+/// Placeholder default transport when the `reqwest` feature is disabled.
+///
+/// It can't be constructed, pick a transport explicitly with
+/// [`VintageStoryModDbApi::with_transport`](crate::VintageStoryModDbApi::with_transport).
+#[cfg(not(feature = "reqwest"))]
+#[derive(Debug)]
+pub enum NoTransport {}
+
+#[cfg(feature = "reqwest")]
+pub use reqwest_transport::ReqwestTransport;
+#[cfg(feature = "blocking")]
+pub(crate) use reqwest_transport::header_pairs;
+
+/// `User-Agent` sent by the built-in reqwest clients, e.g. `vintagestory_mod_db_api/0.1.1`.
+#[cfg(feature = "reqwest")]
+pub(crate) const DEFAULT_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+#[cfg(feature = "reqwest")]
+mod reqwest_transport {
+    use super::*;
+    use reqwest::Client;
+
+    /// The default [`Transport`], backed by a `reqwest::Client`.
+    #[derive(Debug, Clone)]
+    pub struct ReqwestTransport {
+        client: Client,
+    }
+
+    impl Default for ReqwestTransport {
+        /// A transport sending the crate's `User-Agent`. Panics if the client can't be built, like `Client::new`.
+        fn default() -> Self {
+            let client = Client::builder().user_agent(DEFAULT_USER_AGENT).build().expect("failed to build the default HTTP client");
+            Self { client }
+        }
+    }
+
+    impl ReqwestTransport {
+        /// Create a transport using an existing `reqwest::Client`.
+        pub fn new(client: Client) -> Self {
+            Self { client }
+        }
+
+        /// The underlying `reqwest::Client`.
+        pub fn client(&self) -> &Client {
+            &self.client
+        }
+    }
+
+    impl Transport for ReqwestTransport {
+        async fn get(&self, url: &str) -> Result<(u16, String), TransportError> {
+            let resp = self.client.get(url).send().await?;
+            let status = resp.status().as_u16();
+            Ok((status, resp.text().await?))
+        }
+
+        async fn get_response(&self, url: &str) -> Result<TransportResponse, TransportError> {
+            self.get_with_headers(url, &[]).await
+        }
+
+        async fn get_with_headers(&self, url: &str, headers: &[(&str, &str)]) -> Result<TransportResponse, TransportError> {
+            let mut request = self.client.get(url);
+            for (name, value) in headers {
+                request = request.header(*name, *value);
+            }
+            let resp = request.send().await?;
+            let status = resp.status().as_u16();
+            let headers = header_pairs(resp.headers());
+            Ok(TransportResponse { status, body: resp.text().await?, headers })
+        }
+    }
+
+    /// Convert reqwest headers to `(name, value)` pairs, skipping values that aren't valid strings.
+    pub(crate) fn header_pairs(headers: &reqwest::header::HeaderMap) -> Vec<(String, String)> {
+        headers.iter()
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+            .collect()
+    }
+}
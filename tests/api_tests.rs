@@ -47,9 +47,8 @@ async fn test_get_comments() -> Result<(), ApiError> {
     let api = VintageStoryModDbApi::new(false);
     let mods = api.get_mods().await?;
     let first_mod = mods.first().expect("Expected at least one mod");
-    let comments = api.get_comments(first_mod.asset_id).await?;
     // It's OK if no comments exist, we're just testing the endpoint works
-    assert!(comments.len() >= 0);
+    let _comments = api.get_comments(first_mod.asset_id).await?;
     Ok(())
 }
 
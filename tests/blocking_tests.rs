@@ -0,0 +1,58 @@
+#![cfg(feature = "blocking")]
+
+use vintagestory_mod_db_api::*;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const TAGS: &str = r##"{"statuscode":"200","tags":[{"tagid":1,"name":"Magic","color":"#C9C9C9"}]}"##;
+
+#[test]
+fn test_blocking_client() -> Result<(), ApiError> {
+    // The mock server needs a runtime, but the blocking client must be called outside of it
+    let runtime = tokio::runtime::Runtime::new()?;
+    let server = runtime.block_on(async {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/tags"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(TAGS))
+            .mount(&server)
+            .await;
+        server
+    });
+
+    let api = VintageStoryModDbApiBlocking::with_base_url(format!("{}/api", server.uri()), false);
+    assert_eq!(api.get_tags()?[0].name, "Magic");
+    assert!(api.get_authors().is_err());
+    Ok(())
+}
+
+#[test]
+fn test_build_blocking() -> Result<(), ApiError> {
+    let runtime = tokio::runtime::Runtime::new()?;
+    let server = runtime.block_on(async {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/tags"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(TAGS))
+            .expect(1)
+            .mount(&server)
+            .await;
+        server
+    });
+
+    let api = VintageStoryModDbApi::builder()
+        .base_url(format!("{}/api", server.uri()))
+        .enable_cache(true)
+        .timeout(std::time::Duration::from_secs(5))
+        .requests_per_second(50.0)
+        .build_blocking()?;
+    let config = api.config();
+    assert_eq!(config.timeout, Some(std::time::Duration::from_secs(5)));
+    assert_eq!(config.requests_per_second, Some(50.0));
+    assert!(config.user_agent.is_some());
+    assert_eq!(api.get_tags()?[0].name, "Magic");
+    assert_eq!(api.get_tags()?.len(), 1, "tags should be cached");
+
+    assert!(VintageStoryModDbApi::builder().client(reqwest::Client::new()).build_blocking().is_err());
+    Ok(())
+}
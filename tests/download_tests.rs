@@ -0,0 +1,181 @@
+use vintagestory_mod_db_api::api::LinkStatus;
+use vintagestory_mod_db_api::*;
+use wiremock::matchers::{header, header_exists, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const BODY: &[u8] = b"PK\x03\x04 pretend this is a mod archive";
+
+fn release_at(server: &MockServer) -> DetailedModRelease {
+    DetailedModRelease {
+        release_id: 1,
+        main_file: format!("{}/files/testmod.zip", server.uri()),
+        filename: Some("testmod.zip".to_string()),
+        file_id: Some(1),
+        downloads: 0,
+        tags: vec!["v1.20.0".to_string()],
+        mod_id_str: Some("testmod".to_string()),
+        mod_version: "1.0.0".to_string(),
+        created: "2024-01-01 00:00:00".to_string(),
+        changelog: None,
+    }
+}
+
+fn temp_file(name: &str) -> std::path::PathBuf {
+    let path = std::env::temp_dir().join(format!("vsmodapi-{}-{}", std::process::id(), name));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+#[tokio::test]
+async fn test_download_release_to() -> Result<(), ApiError> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET")).and(path("/files/testmod.zip"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(BODY))
+        .mount(&server).await;
+
+    let api = VintageStoryModDbApi::new(false);
+    let dest = temp_file("full.zip");
+    let written = api.download_release_to(&release_at(&server), &dest).await?;
+    assert_eq!(written, BODY.len() as u64);
+    assert_eq!(std::fs::read(&dest)?, BODY);
+    std::fs::remove_file(dest)?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_download_retried() -> Result<(), ApiError> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET")).and(path("/files/testmod.zip"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server).await;
+    Mock::given(method("GET")).and(path("/files/testmod.zip"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(BODY))
+        .expect(1)
+        .mount(&server).await;
+
+    let api = VintageStoryModDbApi::builder()
+        .retry_policy(RetryPolicy { max_retries: 1, base_delay: std::time::Duration::ZERO, backoff_factor: 1.0 })
+        .build()?;
+    let dest = temp_file("retried.zip");
+    assert_eq!(api.download_release_to(&release_at(&server), &dest).await?, BODY.len() as u64);
+    std::fs::remove_file(dest)?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_download_release_into_dir() -> Result<(), ApiError> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET")).and(path("/files/testmod.zip"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(BODY))
+        .mount(&server).await;
+
+    let api = VintageStoryModDbApi::new(false);
+    let dir = temp_file("dir");
+    std::fs::create_dir_all(&dir)?;
+    let mut release = release_at(&server);
+    release.filename = Some("../escape/testmod-1.0.0.zip".to_string());
+    let dest = api.download_release(&release, &dir).await?;
+    assert_eq!(dest, dir.join("testmod-1.0.0.zip"));
+    assert_eq!(std::fs::read(&dest)?, BODY);
+    std::fs::remove_dir_all(dir)?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_resume_release_download() -> Result<(), ApiError> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET")).and(path("/files/testmod.zip")).and(header("range", "bytes=10-"))
+        .respond_with(ResponseTemplate::new(206).set_body_bytes(&BODY[10..]))
+        .mount(&server).await;
+
+    let api = VintageStoryModDbApi::new(false);
+    let dest = temp_file("partial.zip");
+    std::fs::write(&dest, &BODY[..10])?;
+    let size = api.resume_release_download_to(&release_at(&server), &dest).await?;
+    assert_eq!(size, BODY.len() as u64);
+    assert_eq!(std::fs::read(&dest)?, BODY);
+    std::fs::remove_file(dest)?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_resume_without_range_support_restarts() -> Result<(), ApiError> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET")).and(path("/files/testmod.zip")).and(header_exists("range"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(BODY))
+        .expect(1)
+        .mount(&server).await;
+
+    let api = VintageStoryModDbApi::new(false);
+    let dest = temp_file("norange.zip");
+    std::fs::write(&dest, b"garbage")?;
+    let size = api.resume_release_download_to(&release_at(&server), &dest).await?;
+    assert_eq!(size, BODY.len() as u64);
+    assert_eq!(std::fs::read(&dest)?, BODY);
+    std::fs::remove_file(dest)?;
+    Ok(())
+}
+
+#[cfg(feature = "zip")]
+#[tokio::test]
+async fn test_get_dependencies() -> Result<(), ApiError> {
+    use std::io::Write;
+
+    let mut archive = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    archive.start_file("modinfo.json", zip::write::SimpleFileOptions::default()).unwrap();
+    archive.write_all(br#"{"modid": "testmod", "dependencies": {"game": "1.20.0"}}"#)?;
+    let archive = archive.finish().unwrap().into_inner();
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET")).and(path("/files/testmod.zip"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(archive))
+        .mount(&server).await;
+
+    let api = VintageStoryModDbApi::new(false);
+    let deps = api.get_dependencies(&release_at(&server)).await?;
+    assert_eq!(deps, vec![ModDependency { mod_id: "game".to_string(), version: "1.20.0".to_string() }]);
+    Ok(())
+}
+
+fn mod_with_source(mod_id: u32, source_code_url: Option<String>) -> DetailedMod {
+    let simple: SimpleMod = serde_json::from_value(serde_json::json!({
+        "modid": mod_id, "assetid": mod_id + 100, "downloads": 0, "follows": 0, "trendingpoints": 0, "comments": 0,
+        "name": "Mod", "summary": null, "modidstrs": [], "author": "Alice", "urlalias": null, "side": "both",
+        "type": "mod", "logo": null, "tags": [], "lastreleased": "2024-01-01 00:00:00"
+    })).unwrap();
+    DetailedMod { source_code_url, ..DetailedMod::from(simple) }
+}
+
+#[tokio::test]
+async fn test_check_source_links() {
+    let server = MockServer::start().await;
+    for (repo, status) in [("ok", 200), ("gone", 404), ("removed", 410), ("broken", 500)] {
+        Mock::given(method("HEAD")).and(path(format!("/{}", repo)))
+            .respond_with(ResponseTemplate::new(status))
+            .expect(1)
+            .mount(&server).await;
+    }
+
+    let mods = vec![
+        mod_with_source(1, Some(format!("{}/ok", server.uri()))),
+        mod_with_source(2, Some(format!("{}/gone", server.uri()))),
+        mod_with_source(3, Some(format!(" {}/removed ", server.uri()))),
+        mod_with_source(4, Some(format!("{}/broken", server.uri()))),
+        // Nothing listens on port 1, so the connection is refused
+        mod_with_source(5, Some("http://127.0.0.1:1/".to_string())),
+        mod_with_source(6, Some("  ".to_string())),
+        mod_with_source(7, None),
+    ];
+    let mut results = VintageStoryModDbApi::new(false).check_source_links(&mods, 3).await;
+    results.sort_by_key(|(mod_id, _)| *mod_id);
+
+    let ids: Vec<u32> = results.iter().map(|(mod_id, _)| *mod_id).collect();
+    assert_eq!(ids, vec![1, 2, 3, 4, 5], "mods without a source URL should be skipped");
+    assert_eq!(results[0].1, LinkStatus::Ok);
+    assert_eq!(results[1].1, LinkStatus::NotFound);
+    assert_eq!(results[2].1, LinkStatus::NotFound);
+    assert!(matches!(&results[3].1, LinkStatus::Error(e) if e.contains("500")));
+    assert!(matches!(results[4].1, LinkStatus::Error(_)));
+}
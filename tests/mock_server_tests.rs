@@ -0,0 +1,149 @@
+use std::time::Duration;
+use vintagestory_mod_db_api::*;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const TAGS: &str = r##"{"statuscode":"200","tags":[{"tagid":1,"name":"Magic","color":"#C9C9C9"}]}"##;
+
+#[tokio::test]
+async fn test_with_base_url() -> Result<(), ApiError> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/tags"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(TAGS))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let api = VintageStoryModDbApi::with_base_url(format!("{}/api/", server.uri()), false);
+    assert_eq!(api.config().base_url, format!("{}/api", server.uri()));
+    let tags = api.get_tags().await?;
+    assert_eq!(tags[0].name, "Magic");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_builder_timeout() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/tags"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(TAGS).set_delay(Duration::from_secs(5)))
+        .mount(&server)
+        .await;
+
+    let api = VintageStoryModDbApi::builder()
+        .base_url(format!("{}/api", server.uri()))
+        .timeout(Duration::from_millis(200))
+        .build()
+        .unwrap();
+    assert_eq!(api.config().timeout, Some(Duration::from_millis(200)));
+    let err = api.get_tags().await.unwrap_err();
+    assert!(err.is_transient(), "timeouts should be transient: {:?}", err);
+}
+
+#[tokio::test]
+async fn test_retry_after() -> Result<(), ApiError> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/tags"))
+        .respond_with(ResponseTemplate::new(503).insert_header("Retry-After", "1"))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/tags"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(TAGS))
+        .mount(&server)
+        .await;
+
+    let api = VintageStoryModDbApi::builder()
+        .base_url(format!("{}/api", server.uri()))
+        .retry_policy(RetryPolicy { max_retries: 1, base_delay: Duration::ZERO, backoff_factor: 1.0 })
+        .build()?;
+    let start = std::time::Instant::now();
+    assert_eq!(api.get_tags().await?[0].name, "Magic");
+    assert!(start.elapsed() >= Duration::from_secs(1), "Retry-After should be respected");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_custom_client() -> Result<(), ApiError> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/tags"))
+        .and(header("x-app", "launcher"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(TAGS))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert("x-app", reqwest::header::HeaderValue::from_static("launcher"));
+    let client = reqwest::Client::builder().default_headers(headers).build()?;
+    let api = VintageStoryModDbApi::builder()
+        .base_url(format!("{}/api", server.uri()))
+        .client(client.clone())
+        .build()?;
+    assert_eq!(api.config().timeout, None);
+    assert_eq!(api.config().user_agent, None);
+    assert_eq!(api.get_tags().await?[0].name, "Magic");
+
+    let api = VintageStoryModDbApi::with_client(client, true);
+    assert!(api.is_cache_enabled());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_user_agent() -> Result<(), ApiError> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/tags"))
+        .and(header("user-agent", concat!("vintagestory_mod_db_api/", env!("CARGO_PKG_VERSION"))))
+        .respond_with(ResponseTemplate::new(200).set_body_string(TAGS))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/custom/tags"))
+        .and(header("user-agent", "my-launcher/2.0"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(TAGS))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let api = VintageStoryModDbApi::with_base_url(format!("{}/api", server.uri()), false);
+    assert_eq!(api.config().user_agent.as_deref(), Some(concat!("vintagestory_mod_db_api/", env!("CARGO_PKG_VERSION"))));
+    api.get_tags().await?;
+    let api = VintageStoryModDbApi::builder()
+        .base_url(format!("{}/custom", server.uri()))
+        .user_agent("my-launcher/2.0")
+        .build()?;
+    assert_eq!(api.config().user_agent.as_deref(), Some("my-launcher/2.0"));
+    api.get_tags().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_conditional_refresh() -> Result<(), ApiError> {
+    let mods = r#"{"statuscode":"200","mods":[{"modid":1,"assetid":101,"downloads":5,"follows":0,"trendingpoints":0,"comments":0,"name":"Mod","summary":null,"modidstrs":["mod"],"author":"Alice","urlalias":null,"side":"both","type":"mod","logo":null,"tags":[],"lastreleased":"2024-01-01 00:00:00"}]}"#;
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/mods"))
+        .and(header("if-none-match", "\"v1\""))
+        .respond_with(ResponseTemplate::new(304))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/mods"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(mods).insert_header("ETag", "\"v1\""))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let api = VintageStoryModDbApi::with_base_url(format!("{}/api", server.uri()), true);
+    assert!(api.refresh_mods_cache().await?, "first fetch has nothing to compare with");
+    assert!(!api.refresh_mods_cache().await?, "304 should keep the cached list");
+    assert_eq!(api.get_mods().await?[0].mod_id, 1);
+    Ok(())
+}
@@ -0,0 +1,525 @@
+use std::time::Duration;
+use vintagestory_mod_db_api::*;
+
+fn simple_mod(mod_id: u32, downloads: u32, comments: u32) -> SimpleMod {
+    SimpleMod {
+        mod_id,
+        asset_id: mod_id + 1000,
+        downloads,
+        follows: 0,
+        trending_points: 0,
+        comments,
+        name: format!("Mod {}", mod_id),
+        summary: None,
+        mod_id_strs: vec![format!("mod{}", mod_id)],
+        author: "Tester".to_string(),
+        url_alias: None,
+        side: "both".to_string(),
+        mod_type: "mod".to_string(),
+        logo: None,
+        tags: vec![],
+        last_released: "2024-01-01 00:00:00".to_string(),
+        extra: Default::default(),
+    }
+}
+
+fn release(release_id: u32, mod_version: &str, created: &str, tags: &[&str]) -> DetailedModRelease {
+    DetailedModRelease {
+        release_id,
+        main_file: format!("https://mods.vintagestory.at/files/release{}.zip", release_id),
+        filename: Some(format!("release{}.zip", release_id)),
+        file_id: Some(release_id),
+        downloads: 0,
+        tags: tags.iter().map(|t| t.to_string()).collect(),
+        mod_id_str: Some("testmod".to_string()),
+        mod_version: mod_version.to_string(),
+        created: created.to_string(),
+        changelog: None,
+    }
+}
+
+fn detailed_mod(releases: Vec<DetailedModRelease>) -> DetailedMod {
+    let mut detailed = DetailedMod::from(simple_mod(1, 0, 0));
+    detailed.releases = releases;
+    detailed
+}
+
+#[test]
+fn test_engagement_ratio() {
+    assert_eq!(simple_mod(1, 100, 5).engagement_ratio(), 0.05);
+    // No downloads should not divide by zero
+    assert_eq!(simple_mod(2, 0, 3).engagement_ratio(), 3.0);
+}
+
+#[test]
+fn test_sort_by_engagement() {
+    let mut mods = vec![simple_mod(1, 1000, 1), simple_mod(2, 10, 5), simple_mod(3, 100, 2)];
+    sort_by_engagement(&mut mods);
+    let ids: Vec<u32> = mods.iter().map(|m| m.mod_id).collect();
+    assert_eq!(ids, vec![2, 3, 1]);
+}
+
+#[test]
+fn test_download_rate() {
+    let old = simple_mod(1, 100, 0);
+    let new = simple_mod(1, 400, 0);
+    assert_eq!(download_rate(&old, &new, Duration::from_secs(3 * 86_400)), 100.0);
+    assert_eq!(download_rate(&old, &new, Duration::ZERO), 0.0);
+}
+
+#[test]
+fn test_best_release_for() {
+    let detailed = detailed_mod(vec![
+        release(1, "1.0.0", "2024-01-01 10:00:00", &["v1.19.8"]),
+        release(3, "1.2.0", "2024-06-01 10:00:00", &["v1.20.0"]),
+        release(2, "1.1.0", "2024-03-01 10:00:00", &["v1.19.8", "v1.20.0"]),
+    ]);
+    assert_eq!(detailed.best_release_for("v1.19.8").map(|r| r.release_id), Some(2));
+    assert_eq!(detailed.best_release_for("v1.20.0").map(|r| r.release_id), Some(3));
+    assert!(detailed.best_release_for("v1.18.0").is_none());
+}
+
+#[test]
+fn test_recent_releases() {
+    let detailed = detailed_mod(vec![
+        release(1, "1.0.0", "2024-01-01 10:00:00", &[]),
+        release(3, "1.2.0", "2024-06-01 10:00:00", &[]),
+        release(2, "1.1.0", "2024-03-01 10:00:00", &[]),
+    ]);
+    let ids: Vec<u32> = detailed.recent_releases(2).iter().map(|r| r.release_id).collect();
+    assert_eq!(ids, vec![3, 2]);
+    assert_eq!(detailed.recent_releases(10).len(), 3);
+}
+
+#[test]
+fn test_parse_game_version() {
+    let version = ParsedGameVersion::parse("v1.21.0-rc.2").unwrap();
+    assert_eq!((version.major, version.minor, version.patch), (1, 21, 0));
+    assert_eq!(version.prerelease.as_deref(), Some("rc.2"));
+    assert!(!version.is_stable());
+    assert_eq!(ParsedGameVersion::parse("1.20").unwrap().to_string(), "v1.20.0");
+    assert!(ParsedGameVersion::parse("beta").is_none());
+    assert!(ParsedGameVersion::parse("v1.x.2").is_none());
+}
+
+#[test]
+fn test_game_version_ordering() {
+    let parse = |v| ParsedGameVersion::parse(v).unwrap();
+    assert!(parse("v1.20.0") > parse("v1.19.8"));
+    assert!(parse("v1.20.0") > parse("v1.20.0-rc.3"));
+    assert!(parse("v1.20.0-rc.1") > parse("v1.20.0-pre.4"));
+    assert!(parse("v1.20.0-pre.10") > parse("v1.20.0-pre.2"));
+}
+
+#[test]
+fn test_game_version_tags() {
+    let release = release(1, "1.0.0", "2024-01-01 10:00:00", &["v1.19.8", "Beta", "v1.20.0-pre.1"]);
+    assert_eq!(release.game_version_tags(), vec!["v1.19.8", "v1.20.0-pre.1"]);
+}
+
+#[test]
+fn test_sort_mods_by_keys() {
+    let mut mods = vec![simple_mod(1, 10, 0), simple_mod(2, 30, 0), simple_mod(3, 20, 0), simple_mod(4, 5, 0)];
+    mods[1].side = "server".to_string();
+    mods[3].side = "server".to_string();
+    sort_mods_by_keys(&mut mods, &[SortKey::ascending(SortMetric::Side), SortKey::descending(SortMetric::Downloads)]);
+    let ids: Vec<u32> = mods.iter().map(|m| m.mod_id).collect();
+    assert_eq!(ids, vec![3, 1, 2, 4]);
+}
+
+#[test]
+fn test_sort_mods_by_last_released() {
+    let mut mods: Vec<SimpleMod> = ["2024-05-01 9:00:00", "unknown", "2024-05-01 10:00:00", "2023-12-31 23:00:00"].iter().enumerate()
+        .map(|(i, released)| SimpleMod { last_released: released.to_string(), ..simple_mod(i as u32, 0, 0) })
+        .collect();
+    sort_mods_by_keys(&mut mods, &[SortKey::ascending(SortMetric::LastReleased)]);
+    let ids: Vec<u32> = mods.iter().map(|m| m.mod_id).collect();
+    assert_eq!(ids, vec![3, 0, 2, 1]);
+}
+
+#[test]
+fn test_release_channel() {
+    assert_eq!(ReleaseChannel::from_version("1.2.0"), ReleaseChannel::Stable);
+    assert_eq!(ReleaseChannel::from_version("v1.2.0-beta.1"), ReleaseChannel::Beta);
+    assert_eq!(ReleaseChannel::from_version("1.2.0rc2"), ReleaseChannel::ReleaseCandidate);
+    assert_eq!(ReleaseChannel::from_version("0.3.0-dev.4"), ReleaseChannel::Dev);
+    assert_eq!(ReleaseChannel::from_version("2.0.0-pre.2"), ReleaseChannel::Prerelease);
+    assert_eq!(ReleaseChannel::from_version("2.0.0+preview3"), ReleaseChannel::Prerelease);
+    for stable in ["1.2.0-source", "1.2.0-forced", "1.2.0-express", "1.2.0-hotfix2", "1.2.0-devoted"] {
+        assert_eq!(ReleaseChannel::from_version(stable), ReleaseChannel::Stable, "{}", stable);
+    }
+}
+
+#[test]
+fn test_latest_stable_release() {
+    let detailed = detailed_mod(vec![
+        release(1, "1.0.0", "2024-01-01 10:00:00", &["v1.20.0"]),
+        release(2, "1.1.0-rc.1", "2024-03-01 10:00:00", &["v1.20.0"]),
+    ]);
+    assert_eq!(detailed.latest_stable_release("v1.20.0").map(|r| r.release_id), Some(1));
+    assert_eq!(detailed.best_release_for("v1.20.0").map(|r| r.release_id), Some(2));
+}
+
+#[test]
+fn test_sort_by_from_str() {
+    use vintagestory_mod_db_api::api::SortBy;
+
+    assert_eq!("downloads".parse::<SortBy>().unwrap(), SortBy::Downloads);
+    assert_eq!("LastReleased".parse::<SortBy>().unwrap(), SortBy::Released);
+    for sort in [SortBy::Trending, SortBy::Downloads, SortBy::Comments, SortBy::Name, SortBy::Released, SortBy::Created] {
+        assert_eq!(sort.to_string().parse::<SortBy>().unwrap(), sort);
+    }
+    assert!("popularity".parse::<SortBy>().is_err());
+}
+
+#[cfg(feature = "semver")]
+#[test]
+fn test_releases_matching() -> Result<(), ApiError> {
+    let detailed = detailed_mod(vec![
+        release(1, "1.0", "2024-01-01 10:00:00", &[]),
+        release(2, "v1.5.2", "2024-02-01 10:00:00", &[]),
+        release(3, "2.0.0", "2024-03-01 10:00:00", &[]),
+        release(4, "nightly", "2024-04-01 10:00:00", &[]),
+    ]);
+    let ids: Vec<u32> = detailed.releases_matching(">=1.0, <2.0")?.iter().map(|r| r.release_id).collect();
+    assert_eq!(ids, vec![2, 1]);
+    assert!(detailed.releases_matching("not a requirement").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_has_custom_logo() {
+    let mut detailed = detailed_mod(vec![]);
+    assert!(!detailed.has_custom_logo());
+    detailed.logo_file = Some("https://mods.vintagestory.at/web/img/mod-default.png".to_string());
+    assert!(!detailed.has_custom_logo());
+    detailed.logo_file = Some("https://moddbcdn.vintagestory.at/logo_abc123.png".to_string());
+    assert!(detailed.has_custom_logo());
+}
+
+#[test]
+fn test_logo_url() {
+    let mut detailed = detailed_mod(vec![]);
+    assert_eq!(detailed.logo_url(), None);
+    detailed.logo_file = Some("https://mods.vintagestory.at/web/img/mod-default.png".to_string());
+    detailed.logo_filename = Some("logo.png".to_string());
+    assert_eq!(detailed.logo_url(), None, "a bare file name isn't a usable URL");
+    assert!(detailed.has_custom_logo());
+    detailed.logo_file_db = Some("https://cdn.example.com/logo_db.png".to_string());
+    assert_eq!(detailed.logo_url().as_deref(), Some("https://cdn.example.com/logo_db.png"));
+
+    let mut simple = simple_mod(1, 0, 0);
+    simple.logo = Some("mod-default.png".to_string());
+    assert_eq!(simple.logo_url(), None);
+    simple.logo = Some("/files/asset/1/logo.png".to_string());
+    assert_eq!(simple.logo_url().as_deref(), Some("https://moddbcdn.vintagestory.at/files/asset/1/logo.png"));
+}
+
+#[test]
+fn test_parsed_tags() {
+    let release = release(1, "1.0.0", "2024-01-01 10:00:00", &["v1.19.8", "Beta", "v1.20.0-pre.1"]);
+    let tags = release.parsed_tags();
+    assert_eq!(tags.game_versions, vec![ParsedGameVersion::parse("v1.19.8").unwrap(), ParsedGameVersion::parse("v1.20.0-pre.1").unwrap()]);
+    assert_eq!(tags.other, vec!["Beta".to_string()]);
+
+    // Release selection compares parsed versions, so the `v` prefix is optional
+    let detailed = detailed_mod(vec![release]);
+    assert!(detailed.supports_version("1.19.8"));
+    assert!(!detailed.supports_version("1.20.0"));
+    // Tags that aren't versions still match verbatim
+    assert!(detailed.supports_version("Beta"));
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_first_published() {
+    let mut detailed = detailed_mod(vec![
+        release(2, "1.1.0", "2024-03-01 10:00:00", &[]),
+        release(1, "1.0.0", "2024-01-01 10:00:00", &[]),
+        release(3, "1.2.0", "not a date", &[]),
+    ]);
+    // Stubs have a placeholder `created`, so the oldest release is used
+    assert_eq!(detailed.first_published().unwrap().to_string(), "2024-01-01 10:00:00 UTC");
+
+    detailed.is_stub = false;
+    detailed.created = "2023-12-24 08:00:00".to_string();
+    assert_eq!(detailed.first_published().unwrap().to_string(), "2023-12-24 08:00:00 UTC");
+
+    assert!(detailed_mod(vec![]).first_published().is_none());
+}
+
+#[test]
+fn test_popular_releases() {
+    let mut releases = vec![
+        release(1, "1.0.0", "2024-01-01 10:00:00", &[]),
+        release(2, "1.1.0", "2024-03-01 10:00:00", &[]),
+        release(3, "1.2.0", "2024-06-01 10:00:00", &[]),
+    ];
+    releases[0].downloads = 500;
+    releases[1].downloads = 100;
+    releases[2].downloads = 2000;
+    let detailed = detailed_mod(releases);
+    let ids: Vec<u32> = detailed.popular_releases(100).iter().map(|r| r.release_id).collect();
+    assert_eq!(ids, vec![3, 1]);
+}
+
+#[test]
+fn test_release_links() {
+    let mut release = release(7, "1.0.0", "2024-01-01 10:00:00", &[]);
+    assert_eq!(release.download_link(), "https://mods.vintagestory.at/files/release7.zip");
+    release.main_file = "/files/asset/7/release7.zip".to_string();
+    assert_eq!(release.download_link(), "https://mods.vintagestory.at/files/asset/7/release7.zip");
+    assert_eq!(release.release_page_url("betterfarming"), "https://mods.vintagestory.at/betterfarming#tab-files");
+}
+
+#[test]
+fn test_diff_mods() {
+    let old = vec![simple_mod(1, 0, 0), simple_mod(2, 0, 0), simple_mod(3, 0, 0)];
+    let mut new = vec![simple_mod(3, 0, 0), simple_mod(1, 0, 0), simple_mod(4, 0, 0)];
+    new[0].url_alias = Some("mod-three".to_string());
+
+    let diff = diff_mods(&old, &new);
+    assert_eq!(diff.added.iter().map(|m| m.mod_id).collect::<Vec<_>>(), vec![4]);
+    assert_eq!(diff.removed.iter().map(|m| m.mod_id).collect::<Vec<_>>(), vec![2]);
+    assert_eq!(diff.alias_changes, vec![(3, None, Some("mod-three".to_string()))]);
+    assert_eq!(diff_mods(&old, &old), ModsDiff::default());
+}
+
+#[test]
+fn test_supported_versions() {
+    let detailed = detailed_mod(vec![
+        release(1, "1.0.0", "2024-01-01 10:00:00", &["v1.20.0", "v1.19.8"]),
+        release(2, "1.1.0", "2024-03-01 10:00:00", &["1.20.0", "Beta"]),
+    ]);
+    let versions: Vec<String> = detailed.supported_versions().iter().map(|v| v.to_string()).collect();
+    assert_eq!(versions, vec!["v1.19.8", "v1.20.0"]);
+}
+
+#[test]
+fn test_strip_html() {
+    assert_eq!(strip_html("<p>Adds <b>new</b>\n  crops</p><p>Tom &amp; Jerry&nbsp;approved</p>"), "Adds new crops Tom & Jerry approved");
+    assert_eq!(strip_html("a &lt;b&gt; c"), "a <b> c");
+    assert_eq!(strip_html("It&#8217;s &#x2019;quoted&#X2019; &#39;here&#39;"), "It\u{2019}s \u{2019}quoted\u{2019} 'here'");
+    assert_eq!(strip_html("&amp;lt; &#xZZ; &#1114112; & alone"), "&lt; &#xZZ; &#1114112; & alone");
+    assert_eq!(strip_html("<p>a < b and c <= d</p><!-- note --><br/>done"), "a < b and c <= d done");
+}
+
+#[test]
+fn test_summary_preview() {
+    let mut simple = simple_mod(1, 0, 0);
+    assert_eq!(simple.summary_preview(10), "");
+    simple.summary = Some("<p>Adds seasonal crops to the game</p>".to_string());
+    assert_eq!(simple.summary_preview(100), "Adds seasonal crops to the game");
+    assert_eq!(simple.summary_preview(16), "Adds seasonal…");
+    assert_eq!(simple.summary_preview(20), "Adds seasonal crops…");
+    assert_eq!(simple.summary_preview(1), "…");
+    assert_eq!(simple.summary_preview(0), "");
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_timestamp_accessors() {
+    let simple = simple_mod(1, 0, 0);
+    assert_eq!(simple.last_released_at().unwrap().to_string(), "2024-01-01 00:00:00 UTC");
+
+    // Stubs carry placeholder dates, which error instead of panicking
+    let stub = detailed_mod(vec![release(1, "1.0.0", "2024-03-01 10:00:00", &[])]);
+    assert!(matches!(stub.created_at(), Err(ApiError::Unexpected(_))));
+    assert!(stub.last_modified_at().is_err());
+    assert!(stub.last_released_at().is_ok());
+    assert_eq!(stub.releases[0].created_at().unwrap().to_string(), "2024-03-01 10:00:00 UTC");
+}
+
+#[test]
+fn test_serialize_round_trip() {
+    let simple = simple_mod(1, 10, 2);
+    let json = serde_json::to_value(&simple).unwrap();
+    assert_eq!(json["modid"], 1);
+    assert_eq!(json["urlalias"], serde_json::Value::Null);
+    assert_eq!(serde_json::from_value::<SimpleMod>(json).unwrap(), simple);
+
+    let mut release = release(1, "1.0.0", "2024-01-01 10:00:00", &["v1.20.0"]);
+    release.filename = None;
+    let detailed = detailed_mod(vec![release]);
+    let json = serde_json::to_string(&detailed).unwrap();
+    let parsed: DetailedMod = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, detailed);
+    assert!(parsed.is_stub);
+    assert_eq!(parsed.releases[0].filename, None);
+
+    let fetched = DetailedMod { is_stub: false, ..detailed };
+    let json = serde_json::to_value(&fetched).unwrap();
+    assert!(json.get("is_stub").is_none());
+    assert!(!serde_json::from_value::<DetailedMod>(json).unwrap().is_stub);
+}
+
+#[cfg(feature = "semver")]
+#[test]
+fn test_latest_release_by_version() {
+    let detailed = detailed_mod(vec![
+        release(1, "1.10.0", "2024-01-01 10:00:00", &[]),
+        release(2, "1.9", "2024-02-01 10:00:00", &[]),
+        release(3, "nightly", "2024-03-01 10:00:00", &[]),
+    ]);
+    assert_eq!(detailed.releases[1].semver().unwrap().to_string(), "1.9.0");
+    assert!(detailed.releases[2].semver().is_err());
+    assert_eq!(detailed.latest_release_by_version().map(|r| r.release_id), Some(1));
+}
+
+#[test]
+fn test_game_version_is_newer_than() {
+    let version = |name: &str| GameVersion { tag_id: 0, name: name.to_string(), color: String::new() };
+    assert!(version("v1.20.0").is_newer_than(&version("v1.9.15")));
+    assert!(version("1.20.0").is_newer_than(&version("v1.20.0-rc.1")));
+    assert!(!version("v1.20.0-pre.1").is_newer_than(&version("v1.20.0-rc.1")));
+    assert!(!version("unknown").is_newer_than(&version("v1.0.0")));
+}
+
+#[cfg(feature = "semver")]
+#[test]
+fn test_game_version_parse_version() {
+    let version = |name: &str| GameVersion { tag_id: 0, name: name.to_string(), color: String::new() }.parse_version();
+    assert_eq!(version("v1.21.0-rc.2").unwrap().to_string(), "1.21.0-rc.2");
+    assert!(version("v1.21.0-pre.10").unwrap() > version("v1.21.0-pre.2").unwrap());
+    assert!(version("v1.21.0-rc.1").unwrap() > version("v1.21.0-pre.4").unwrap());
+    assert!(version("beta").is_none());
+}
+
+#[test]
+fn test_parse_modinfo_dependencies() -> Result<(), ApiError> {
+    let modinfo = "\u{feff}{\"type\": \"code\", \"modid\": \"testmod\", \"Dependencies\": {\"game\": \"1.20.0\", \"betterfarming\": \"*\"}}";
+    let deps = ModDependency::parse_modinfo(modinfo)?;
+    assert_eq!(deps, vec![
+        ModDependency { mod_id: "betterfarming".to_string(), version: "*".to_string() },
+        ModDependency { mod_id: "game".to_string(), version: "1.20.0".to_string() },
+    ]);
+    assert!(ModDependency::parse_modinfo(r#"{"modid": "standalone"}"#)?.is_empty());
+    assert!(ModDependency::parse_modinfo("not json").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_parse_modinfo_comments_and_trailing_commas() -> Result<(), ApiError> {
+    let modinfo = r#"{
+        // Written by hand, like most modinfo files
+        "type": "code",
+        "modid": "testmod", /* "game" lives here */
+        "website": "https://example.com/a//b",
+        "description": "Commas, } and ] in strings \" stay, /* too */",
+        "dependencies": {
+            "game": "1.20.0", // "1.19.8" before
+            "betterfarming": "*",
+        },
+    }"#;
+    let deps = ModDependency::parse_modinfo(modinfo)?;
+    assert_eq!(deps, vec![
+        ModDependency { mod_id: "betterfarming".to_string(), version: "*".to_string() },
+        ModDependency { mod_id: "game".to_string(), version: "1.20.0".to_string() },
+    ]);
+    assert!(ModDependency::parse_modinfo(r#"{"dependencies": {"game": "1.20.0",,}}"#).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_screenshot_urls() {
+    let mut screenshot = DetailedModScreenshot {
+        file_id: 1,
+        main_file: "https://moddbcdn.vintagestory.at/shots/1.png".to_string(),
+        filename: "1.png".to_string(),
+        thumbnail_filename: "1_thumb.png".to_string(),
+        created: "2024-01-01 00:00:00".to_string(),
+    };
+    assert_eq!(screenshot.image_url(), "https://moddbcdn.vintagestory.at/shots/1.png");
+    assert_eq!(screenshot.thumbnail_url(), "https://moddbcdn.vintagestory.at/shots/1_thumb.png");
+
+    screenshot.main_file = "/shots/2.png".to_string();
+    screenshot.thumbnail_filename = "thumbs/2_thumb.png".to_string();
+    assert_eq!(screenshot.image_url(), "https://moddbcdn.vintagestory.at/shots/2.png");
+    assert_eq!(screenshot.thumbnail_url(), "https://moddbcdn.vintagestory.at/thumbs/2_thumb.png");
+}
+
+#[test]
+fn test_mod_side() -> Result<(), serde_json::Error> {
+    let mut simple = simple_mod(1, 0, 0);
+    assert_eq!(simple.side(), ModSide::Both);
+    simple.side = "Client".to_string();
+    assert_eq!(simple.side(), ModSide::Client);
+    simple.side = "universal".to_string();
+    assert_eq!(simple.side(), ModSide::Unknown("universal".to_string()));
+    assert_eq!(detailed_mod(vec![]).side(), ModSide::Both);
+
+    let sides: Vec<ModSide> = serde_json::from_str(r#"["server", "both", "other"]"#)?;
+    assert_eq!(sides, vec![ModSide::Server, ModSide::Both, ModSide::Unknown("other".to_string())]);
+    assert_eq!(serde_json::to_string(&sides)?, r#"["server","both","other"]"#);
+    Ok(())
+}
+
+#[test]
+fn test_mod_type() -> Result<(), serde_json::Error> {
+    let mut simple = simple_mod(1, 0, 0);
+    assert_eq!(simple.mod_type(), ModType::Mod);
+    simple.mod_type = "externaltool".to_string();
+    assert_eq!(simple.mod_type(), ModType::ExternalTool);
+    simple.mod_type = "Theme".to_string();
+    assert_eq!(simple.mod_type(), ModType::Theme);
+    simple.mod_type = "modpack".to_string();
+    assert_eq!(simple.mod_type(), ModType::Other("modpack".to_string()));
+    assert_eq!(DetailedMod::from(simple).mod_type(), ModType::Other("modpack".to_string()));
+
+    let types: Vec<ModType> = serde_json::from_str(r#"["mod", "externaltool", "theme", "shader"]"#)?;
+    assert_eq!(types, vec![ModType::Mod, ModType::ExternalTool, ModType::Theme, ModType::Other("shader".to_string())]);
+    assert_eq!(serde_json::to_string(&types)?, r#"["mod","externaltool","theme","shader"]"#);
+    Ok(())
+}
+
+#[test]
+fn test_changelog_plain() {
+    let mut r = release(1, "1.0.0", "2024-01-01 00:00:00", &[]);
+    assert_eq!(r.changelog_plain(), None);
+    r.changelog = Some(String::new());
+    assert_eq!(r.changelog_plain(), None);
+    r.changelog = Some("<p> </p>".to_string());
+    assert_eq!(r.changelog_plain(), None);
+    r.changelog = Some("<ul><li>Fixed <b><i>crash</i></b> on load</li><li>Tweaked &quot;drops&quot;</li></ul>".to_string());
+    assert_eq!(r.changelog_plain().as_deref(), Some(r#"Fixed crash on load Tweaked "drops""#));
+    assert!(r.changelog.as_deref().unwrap().starts_with("<ul>"), "raw changelog should be kept");
+}
+
+#[test]
+fn test_author_display_name() {
+    let mut author = Author { userid: 1, name: Some(" Alice ".to_string()) };
+    assert_eq!(author.display_name(), "Alice");
+    author.name = Some("  ".to_string());
+    assert_eq!(author.display_name(), Author::UNNAMED);
+    author.name = None;
+    assert_eq!(author.display_name_or("user #1"), "user #1");
+}
+
+#[test]
+fn test_unknown_fields_kept() -> Result<(), serde_json::Error> {
+    let mut json = serde_json::to_value(simple_mod(1, 0, 0))?;
+    assert!(json.get("extra").is_none());
+    json["featured"] = serde_json::json!(true);
+    let simple: SimpleMod = serde_json::from_value(json.clone())?;
+    assert_eq!(simple.extra["featured"], true);
+    assert_eq!(serde_json::to_value(&simple)?, json);
+
+    let detailed = DetailedMod::from(simple);
+    assert_eq!(detailed.extra["featured"], true);
+    let parsed: DetailedMod = serde_json::from_str(&serde_json::to_string(&detailed)?)?;
+    assert_eq!(parsed.extra.len(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_releases_sorted_by_parsed_date() {
+    let detailed = detailed_mod(vec![
+        release(1, "1.0.0", "2024-02-01 9:00:00", &[]),
+        release(2, "1.1.0", "2024-02-01 10:00:00", &[]),
+        release(3, "1.2.0", "not a date", &[]),
+        release(4, "1.3.0", "", &[]),
+        release(5, "0.9.0", "2024-02-01 10:00:00", &[]),
+    ]);
+    let ids: Vec<u32> = detailed.releases_sorted().iter().map(|r| r.release_id).collect();
+    assert_eq!(ids, vec![5, 2, 1, 4, 3]);
+}
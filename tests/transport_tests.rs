@@ -0,0 +1,1011 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use vintagestory_mod_db_api::api::SortBy;
+use vintagestory_mod_db_api::*;
+
+/// Serves canned responses by URL and records every request.
+#[derive(Default)]
+struct FakeTransport {
+    responses: HashMap<String, (u16, String)>,
+    requests: Mutex<Vec<String>>,
+}
+
+impl FakeTransport {
+    fn with(mut self, url: &str, status: u16, body: &str) -> Self {
+        self.responses.insert(url.to_string(), (status, body.to_string()));
+        self
+    }
+
+    fn request_count(&self) -> usize {
+        self.requests.lock().unwrap().len()
+    }
+}
+
+impl Transport for FakeTransport {
+    async fn get(&self, url: &str) -> Result<(u16, String), TransportError> {
+        self.requests.lock().unwrap().push(url.to_string());
+        self.responses.get(url).cloned().ok_or_else(|| TransportError::Connect(format!("no route to {}", url)))
+    }
+}
+
+impl Transport for &FakeTransport {
+    async fn get(&self, url: &str) -> Result<(u16, String), TransportError> {
+        (*self).get(url).await
+    }
+}
+
+const MODS: &str = r##"{"statuscode":"200","mods":[
+    {"modid":1,"assetid":101,"downloads":500,"follows":10,"trendingpoints":5,"comments":2,"name":"Better Farming","summary":"More crops","modidstrs":["betterfarming"],"author":"Alice","urlalias":"betterfarming","side":"both","type":"mod","logo":null,"tags":["Farming","QoL"],"lastreleased":"2024-05-01 10:00:00"},
+    {"modid":2,"assetid":102,"downloads":50,"follows":1,"trendingpoints":0,"comments":9,"name":"Crop Rotation","summary":"Improves farming yields","modidstrs":["croprotation"],"author":"Bob","urlalias":null,"side":"server","type":"mod","logo":null,"tags":["farming"],"lastreleased":"2023-11-20 08:30:00"},
+    {"modid":3,"assetid":103,"downloads":5000,"follows":100,"trendingpoints":50,"comments":40,"name":"Mod Manager","summary":null,"modidstrs":[],"author":"Alice","urlalias":"modmanager","side":"client","type":"externaltool","logo":null,"tags":[],"lastreleased":"2024-06-10 12:00:00"}
+]}"##;
+
+/// Releases are `(release_id, mod_version, created, game version tags)`.
+fn mod_json(mod_id: u32, wiki_url: Option<&str>, releases: &[(u32, &str, &str, &[&str])]) -> String {
+    let releases: Vec<String> = releases.iter().map(|(id, version, created, tags)| format!(
+        r#"{{"releaseid":{id},"mainfile":"https://mods.vintagestory.at/files/{id}.zip","filename":"mod{id}.zip","fileid":{id},"downloads":10,"tags":[{tags}],"modidstr":"mod{mod_id}","modversion":"{version}","created":"{created}","changelog":null}}"#,
+        tags = tags.iter().map(|t| format!("\"{}\"", t)).collect::<Vec<_>>().join(","),
+    )).collect();
+    format!(
+        r#"{{"statuscode":"200","mod":{{"modid":{mod_id},"assetid":{asset_id},"name":"Mod {mod_id}","text":"Description","author":"Alice","urlalias":null,"logofilename":null,"logofile":null,"logofiledb":null,"homepageurl":null,"sourcecodeurl":null,"trailervideourl":null,"issuetrackerurl":null,"wikiurl":{wiki},"downloads":100,"follows":1,"trendingpoints":0,"comments":0,"side":"both","type":"mod","created":"2023-01-01 00:00:00","lastreleased":"2024-01-01 00:00:00","lastmodified":"2024-01-01 00:00:00","tags":[],"releases":[{releases}],"screenshots":[]}}}}"#,
+        asset_id = mod_id + 100,
+        wiki = wiki_url.map(|w| format!("\"{}\"", w)).unwrap_or("null".to_string()),
+        releases = releases.join(","),
+    )
+}
+
+/// The three mods from `MODS` with details, only mod 1 has a wiki and only mod 2 supports v1.20.0.
+fn catalog() -> FakeTransport {
+    FakeTransport::default()
+        .with("https://mods.vintagestory.at/api/mods", 200, MODS)
+        .with("https://mods.vintagestory.at/api/mod/1", 200, &mod_json(1, Some("https://wiki.example/1"), &[(11, "1.0.0", "2024-01-01 00:00:00", &["v1.19.8"])]))
+        .with("https://mods.vintagestory.at/api/mod/2", 200, &mod_json(2, Some("  "), &[(21, "2.0.0", "2024-02-01 00:00:00", &["v1.19.8", "v1.20.0"])]))
+        .with("https://mods.vintagestory.at/api/mod/3", 200, &mod_json(3, None, &[]))
+}
+
+const TAGS: &str = r##"{"statuscode":"200","tags":[{"tagid":1,"name":"Magic","color":"#C9C9C9"},{"tagid":2,"name":"QoL","color":"#C9C9C9"}]}"##;
+
+#[tokio::test]
+async fn test_custom_transport() -> Result<(), ApiError> {
+    let transport = FakeTransport::default().with("https://mods.vintagestory.at/api/tags", 200, TAGS);
+    let api = VintageStoryModDbApi::with_transport(&transport, false);
+    let tags = api.get_tags().await?;
+    assert_eq!(tags.iter().map(|t| t.name.as_str()).collect::<Vec<_>>(), vec!["Magic", "QoL"]);
+    assert_eq!(transport.request_count(), 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_custom_transport_errors() {
+    let transport = FakeTransport::default().with("https://mods.vintagestory.at/api/tags", 503, "<html>Service Unavailable</html>");
+    let api = VintageStoryModDbApi::with_transport(&transport, false);
+    let err = api.get_tags().await.unwrap_err();
+    assert!(matches!(err, ApiError::HttpStatus { status: 503, .. }));
+    assert!(err.is_transient());
+
+    let err = api.get_authors().await.unwrap_err();
+    assert!(matches!(err, ApiError::Transport(TransportError::Connect(_))));
+    assert!(err.is_transient());
+}
+
+#[tokio::test]
+async fn test_search_text_local() -> Result<(), ApiError> {
+    let transport = FakeTransport::default().with("https://mods.vintagestory.at/api/mods", 200, MODS);
+    let api = VintageStoryModDbApi::with_transport(&transport, true);
+    let ids: Vec<u32> = api.search_text_local("farming").await?.iter().map(|m| m.mod_id).collect();
+    // Name match first, then summary match
+    assert_eq!(ids, vec![1, 2]);
+    assert!(api.search_text_local("  ").await?.is_empty());
+    assert_eq!(transport.request_count(), 1, "cached mods list should be reused");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_documented_mods() -> Result<(), ApiError> {
+    let transport = catalog();
+    let api = VintageStoryModDbApi::with_transport(&transport, true);
+    let documented = api.get_documented_mods(2).await?;
+    assert_eq!(documented.iter().map(|m| m.mod_id).collect::<Vec<_>>(), vec![1]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_outdated_mods() -> Result<(), ApiError> {
+    let transport = catalog();
+    let api = VintageStoryModDbApi::with_transport(&transport, true);
+    let outdated = api.get_outdated_mods("v1.20.0", 3).await?;
+    assert_eq!(outdated.iter().map(|m| m.mod_id).collect::<Vec<_>>(), vec![1, 3]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_catalog_fingerprint() -> Result<(), ApiError> {
+    let transport = FakeTransport::default().with("https://mods.vintagestory.at/api/mods", 200, MODS);
+    let first = VintageStoryModDbApi::with_transport(&transport, false).catalog_fingerprint().await?;
+    let second = VintageStoryModDbApi::with_transport(&transport, false).catalog_fingerprint().await?;
+    assert_eq!(first, second);
+
+    let changed = FakeTransport::default().with("https://mods.vintagestory.at/api/mods", 200, &MODS.replace(r#""downloads":500"#, r#""downloads":501"#));
+    assert_ne!(first, VintageStoryModDbApi::with_transport(&changed, false).catalog_fingerprint().await?);
+    Ok(())
+}
+
+#[cfg(feature = "chrono")]
+#[tokio::test]
+async fn test_comment_histogram() -> Result<(), ApiError> {
+    use chrono::NaiveDate;
+
+    let comments = r#"{"statuscode":"200","comments":[
+        {"commentid":1,"assetid":101,"userid":5,"text":"a","created":"2024-05-06 10:00:00","lastmodified":"2024-05-06 10:00:00"},
+        {"commentid":2,"assetid":101,"userid":5,"text":"b","created":"2024-05-12 23:59:59","lastmodified":"2024-05-12 23:59:59"},
+        {"commentid":3,"assetid":101,"userid":6,"text":"c","created":"2024-05-13 00:00:00","lastmodified":"2024-05-13 00:00:00"},
+        {"commentid":4,"assetid":101,"userid":6,"text":"d","created":"yesterday","lastmodified":"yesterday"}
+    ]}"#;
+    let transport = FakeTransport::default().with("https://mods.vintagestory.at/api/comments/101", 200, comments);
+    let api = VintageStoryModDbApi::with_transport(&transport, false);
+
+    let weekly = api.comment_histogram(101, Bucket::Week).await?;
+    let date = |d| NaiveDate::from_ymd_opt(2024, 5, d).unwrap();
+    assert_eq!(weekly.into_iter().collect::<Vec<_>>(), vec![(date(6), 2), (date(13), 1)]);
+    assert_eq!(api.comment_histogram(101, Bucket::Month).await?.get(&date(1)), Some(&3));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_mod_from_alias_cached() -> Result<(), ApiError> {
+    let transport = catalog();
+    let api = VintageStoryModDbApi::with_transport(&transport, true);
+    api.get_mods().await?;
+
+    assert_eq!(api.get_mod_from_alias_cached("BetterFarming").await?.mod_id, 1);
+    assert!(api.get_mod_from_alias_cached("removedmod").await.is_err());
+    assert_eq!(transport.request_count(), 2, "stale alias should not be fetched");
+    Ok(())
+}
+
+#[test]
+fn test_asset_urls() {
+    let transport = FakeTransport::default();
+    let api = VintageStoryModDbApi::with_transport(&transport, false).with_asset_host("https://cdn.example.com/");
+    assert_eq!(api.asset_url("/files/logo.png"), "https://cdn.example.com/files/logo.png");
+    assert_eq!(api.asset_url("https://other.example/logo.png"), "https://other.example/logo.png");
+
+    let screenshot = DetailedModScreenshot {
+        file_id: 1,
+        main_file: "shots/1.png".to_string(),
+        filename: "1.png".to_string(),
+        thumbnail_filename: "shots/1_thumb.png".to_string(),
+        created: "2024-01-01 00:00:00".to_string(),
+    };
+    assert_eq!(api.screenshot_url(&screenshot), "https://cdn.example.com/shots/1.png");
+    assert_eq!(api.screenshot_thumbnail_url(&screenshot), "https://cdn.example.com/shots/1_thumb.png");
+}
+
+#[tokio::test]
+async fn test_get_mods_for_tags_union() -> Result<(), ApiError> {
+    let tags = TAGS.replace("Magic", "Farming");
+    let transport = FakeTransport::default()
+        .with("https://mods.vintagestory.at/api/mods", 200, MODS)
+        .with("https://mods.vintagestory.at/api/tags", 200, &tags);
+    let api = VintageStoryModDbApi::with_transport(&transport, true);
+
+    let ids: Vec<u32> = api.get_mods_for_tags_union(&[1, 2, 99]).await?.iter().map(|m| m.mod_id).collect();
+    // Mod 1 has both tags but is listed once, mod 2 matches "farming" case-insensitively
+    assert_eq!(ids, vec![1, 2]);
+    assert!(api.get_mods_for_tags_union(&[99]).await?.is_empty());
+    Ok(())
+}
+
+#[cfg(feature = "random")]
+#[tokio::test]
+async fn test_random_from_empty_catalog() {
+    let transport = FakeTransport::default()
+        .with("https://mods.vintagestory.at/api/mods", 200, r#"{"statuscode":"200","mods":[]}"#)
+        .with("https://mods.vintagestory.at/api/comments/101", 200, r#"{"statuscode":"200","comments":[]}"#);
+    let api = VintageStoryModDbApi::with_transport(&transport, false);
+    assert!(matches!(api.get_random_mod().await, Err(ApiError::Empty("mods"))));
+    assert!(api.get_random_comment(101).await.unwrap().is_none());
+}
+
+#[cfg(feature = "random")]
+#[tokio::test]
+async fn test_random_simple_mod() -> Result<(), ApiError> {
+    let transport = FakeTransport::default().with("https://mods.vintagestory.at/api/mods", 200, MODS);
+    let api = VintageStoryModDbApi::with_transport(&transport, false);
+    let selected = api.get_random_simple_mod().await?;
+    assert!((1..=3).contains(&selected.mod_id));
+    assert_eq!(transport.request_count(), 1, "details should not be fetched");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_filter_by_tag() -> Result<(), ApiError> {
+    let transport = FakeTransport::default().with("https://mods.vintagestory.at/api/mods", 200, MODS);
+    let api = VintageStoryModDbApi::with_transport(&transport, true);
+    assert_eq!(api.filter_by_tag(&[3, 2, 1, 42], "FARMING").await?, vec![2, 1]);
+    assert_eq!(api.filter_by_tag(&[1, 2, 3], "qol").await?, vec![1]);
+    assert_eq!(transport.request_count(), 1);
+    Ok(())
+}
+
+#[cfg(feature = "chrono")]
+#[tokio::test]
+async fn test_get_mods_released_within() -> Result<(), ApiError> {
+    use chrono::{Duration, Utc};
+
+    let days_ago = |days| (Utc::now() - Duration::days(days)).format("%Y-%m-%d %H:%M:%S").to_string();
+    let mods = MODS
+        .replace("2023-11-20 08:30:00", &days_ago(3))
+        .replace("2024-06-10 12:00:00", &days_ago(1));
+    let transport = FakeTransport::default().with("https://mods.vintagestory.at/api/mods", 200, &mods);
+    let api = VintageStoryModDbApi::with_transport(&transport, true);
+
+    let ids: Vec<u32> = api.get_mods_released_within(7).await?.iter().map(|m| m.mod_id).collect();
+    assert_eq!(ids, vec![3, 2]);
+    assert_eq!(api.get_mods_released_within(2).await?.len(), 1);
+    Ok(())
+}
+
+#[cfg(feature = "chrono")]
+#[tokio::test]
+async fn test_get_mods_updated_since() -> Result<(), ApiError> {
+    use chrono::{TimeZone, Utc};
+
+    let mods = MODS.replace("2023-11-20 08:30:00", "not a date");
+    let transport = FakeTransport::default().with("https://mods.vintagestory.at/api/mods", 200, &mods);
+    let api = VintageStoryModDbApi::with_transport(&transport, true);
+
+    let since = Utc.with_ymd_and_hms(2024, 5, 1, 10, 0, 0).unwrap();
+    let ids: Vec<u32> = api.get_mods_updated_since(since).await?.iter().map(|m| m.mod_id).collect();
+    assert_eq!(ids, vec![3]);
+    let since = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+    assert_eq!(api.get_mods_updated_since(since).await?.len(), 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_version_adoption() -> Result<(), ApiError> {
+    let transport = catalog();
+    let api = VintageStoryModDbApi::with_transport(&transport, true);
+    assert_eq!(api.version_adoption("v1.20.0", 2).await?, 1.0 / 3.0);
+    assert_eq!(api.version_adoption("1.19.8", 2).await?, 2.0 / 3.0);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_mod_by_asset_id() -> Result<(), ApiError> {
+    let transport = FakeTransport::default().with("https://mods.vintagestory.at/api/mods", 200, MODS);
+    let api = VintageStoryModDbApi::with_transport(&transport, true);
+    assert_eq!(api.get_mod_by_asset_id(102).await?.map(|m| m.mod_id), Some(2));
+    assert!(api.get_mod_by_asset_id(2).await?.is_none());
+    assert_eq!(transport.request_count(), 1);
+
+    let uncached = VintageStoryModDbApi::with_transport(&transport, false);
+    assert_eq!(uncached.get_mod_by_asset_id(103).await?.map(|m| m.mod_id), Some(3));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mods_per_game_version() -> Result<(), ApiError> {
+    let transport = catalog();
+    let api = VintageStoryModDbApi::with_transport(&transport, true);
+    let counts = api.mods_per_game_version(3).await?;
+    assert_eq!(counts.into_iter().collect::<Vec<_>>(), vec![("v1.19.8".to_string(), 2), ("v1.20.0".to_string(), 1)]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_mods_with_screenshots() -> Result<(), ApiError> {
+    let screenshot = r#""screenshots":[{"fileid":5,"mainfile":"https://moddbcdn.vintagestory.at/shot.png","filename":"shot.png","thumbnailfilename":"shot_thumb.png","created":"2024-01-01 00:00:00"}]"#;
+    let transport = catalog().with("https://mods.vintagestory.at/api/mod/3", 200, &mod_json(3, None, &[]).replace(r#""screenshots":[]"#, screenshot));
+    let api = VintageStoryModDbApi::with_transport(&transport, true);
+    let ids: Vec<u32> = api.get_mods_with_screenshots(2).await?.iter().map(|m| m.mod_id).collect();
+    assert_eq!(ids, vec![3]);
+    Ok(())
+}
+
+#[test]
+fn test_config() {
+    let transport = FakeTransport::default();
+    let config = VintageStoryModDbApi::with_transport(&transport, true).with_asset_host("https://cdn.example.com").config();
+    assert_eq!(config.base_url, "https://mods.vintagestory.at/api");
+    assert_eq!(config.asset_host, "https://cdn.example.com");
+    assert!(config.enable_cache);
+}
+
+#[tokio::test]
+async fn test_api_status_code() {
+    let transport = FakeTransport::default()
+        .with("https://mods.vintagestory.at/api/mod/404", 200, r#"{"statuscode":"404"}"#)
+        .with("https://mods.vintagestory.at/api/tags", 200, r#"{"statuscode":"500","tags":[]}"#);
+    let api = VintageStoryModDbApi::with_transport(&transport, false);
+
+    let err = api.get_mod(404).await.unwrap_err();
+    assert!(matches!(&err, ApiError::NotFound { endpoint } if endpoint == "/mod"), "{:?}", err);
+    assert!(!err.is_transient());
+
+    let err = api.get_tags().await.unwrap_err();
+    assert!(matches!(&err, ApiError::Status { code, .. } if code == "500"), "{:?}", err);
+    assert!(err.is_transient());
+}
+
+#[tokio::test]
+async fn test_every_endpoint_checks_status() {
+    let base = "https://mods.vintagestory.at/api";
+    let failing = r#"{"statuscode":"500"}"#;
+    let transport = ["/mods", "/mod/1", "/tags", "/authors", "/gameversions", "/comments/101"].into_iter()
+        .fold(FakeTransport::default(), |t, path| t.with(&format!("{}{}", base, path), 200, failing));
+    let api = VintageStoryModDbApi::with_transport(&transport, false);
+
+    let endpoint = |err: ApiError| match err {
+        ApiError::Status { code, endpoint } if code == "500" => endpoint,
+        other => panic!("{:?}", other),
+    };
+    assert_eq!(endpoint(api.get_mods().await.unwrap_err()), "/mods");
+    assert_eq!(endpoint(api.get_mod(1).await.unwrap_err()), "/mod");
+    assert_eq!(endpoint(api.get_tags().await.unwrap_err()), "/tags");
+    assert_eq!(endpoint(api.get_authors().await.unwrap_err()), "/authors");
+    assert_eq!(endpoint(api.get_game_versions().await.unwrap_err()), "/gameversions");
+    assert_eq!(endpoint(api.get_comments(101).await.unwrap_err()), "/comments");
+}
+
+#[tokio::test]
+async fn test_http_not_found() {
+    let transport = FakeTransport::default().with("https://mods.vintagestory.at/api/mod/9", 404, "<html>Not Found</html>");
+    let api = VintageStoryModDbApi::with_transport(&transport, false);
+    let err = api.get_mod(9).await.unwrap_err();
+    assert!(matches!(&err, ApiError::NotFound { endpoint } if endpoint == "/mod"), "{:?}", err);
+    assert!(!err.is_transient());
+}
+
+#[tokio::test]
+async fn test_decode_error_keeps_body() {
+    let body = format!(r#"{{"statuscode":"200","tags":"{}"}}"#, "x".repeat(1000));
+    let transport = FakeTransport::default()
+        .with("https://mods.vintagestory.at/api/tags", 200, &body)
+        .with("https://mods.vintagestory.at/api/authors", 200, "<html>Maintenance</html>");
+    let api = VintageStoryModDbApi::with_transport(&transport, false);
+
+    let err = api.get_tags().await.unwrap_err();
+    let ApiError::Decode { endpoint, body_snippet, .. } = &err else { panic!("{:?}", err) };
+    assert_eq!(endpoint, "/tags");
+    assert!(body_snippet.starts_with(r#"{"statuscode":"200""#) && body_snippet.ends_with("...") && body_snippet.len() < 400);
+    assert!(!err.is_transient());
+
+    let err = api.get_authors().await.unwrap_err();
+    assert!(matches!(&err, ApiError::Decode { body_snippet, .. } if body_snippet == "<html>Maintenance</html>"), "{:?}", err);
+    assert!(err.to_string().contains("<html>Maintenance</html>"));
+}
+
+#[tokio::test]
+async fn test_search_mods_encodes_query() -> Result<(), ApiError> {
+    let url = "https://mods.vintagestory.at/api/mods?text=better+%26+more%23&sortby=downloads&sortdir=d&side=&userid=0&mv=";
+    let transport = FakeTransport::default().with(url, 200, MODS);
+    let api = VintageStoryModDbApi::with_transport(&transport, false);
+    assert_eq!(api.search_mods("better & more#", false, SortBy::Downloads).await?.len(), 3);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_most_recent_game_version_empty() {
+    let transport = FakeTransport::default().with("https://mods.vintagestory.at/api/gameversions", 200, r#"{"statuscode":"200","gameversions":[]}"#);
+    let api = VintageStoryModDbApi::with_transport(&transport, false);
+    assert!(matches!(api.get_most_recent_game_version().await, Err(ApiError::Empty("game versions"))));
+}
+
+#[tokio::test]
+async fn test_most_recent_stable_game_version() -> Result<(), ApiError> {
+    let versions = |names: &[&str]| format!(
+        r#"{{"statuscode":"200","gameversions":[{}]}}"#,
+        names.iter().enumerate().map(|(i, name)| format!(r##"{{"tagid":{},"name":"{}","color":"#CCCCCC"}}"##, i, name)).collect::<Vec<_>>().join(","),
+    );
+    let url = "https://mods.vintagestory.at/api/gameversions";
+
+    let transport = FakeTransport::default().with(url, 200, &versions(&["v1.20.0", "v1.19.8", "v1.20.1-rc.1", "v1.21.0-pre.2", "v1.9.15"]));
+    let api = VintageStoryModDbApi::with_transport(&transport, false);
+    assert_eq!(api.get_most_recent_stable_game_version().await?.name, "v1.20.0");
+
+    let transport = FakeTransport::default().with(url, 200, &versions(&["v1.21.0-pre.1", "v1.21.0-rc.1", "v1.21.0-dev.3"]));
+    let api = VintageStoryModDbApi::with_transport(&transport, false);
+    assert!(matches!(api.get_most_recent_stable_game_version().await, Err(ApiError::Empty(_))));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_releases_for_version() -> Result<(), ApiError> {
+    let transport = FakeTransport::default().with("https://mods.vintagestory.at/api/mod/1", 200, &mod_json(1, None, &[
+        (11, "1.0.0", "2024-01-01 00:00:00", &["v1.19.8"]),
+        (13, "1.2.0", "2024-03-01 00:00:00", &["v1.20.0"]),
+        (12, "1.1.0", "2024-02-01 00:00:00", &["v1.19.8", "v1.20.0"]),
+    ]));
+    let api = VintageStoryModDbApi::with_transport(&transport, false);
+    let ids: Vec<u32> = api.get_releases_for_version(1, "v1.20.0").await?.iter().map(|r| r.release_id).collect();
+    assert_eq!(ids, vec![13, 12]);
+    assert!(api.get_releases_for_version(1, "v1.18.0").await?.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_mod_from_alias_normalized() -> Result<(), ApiError> {
+    let transport = FakeTransport::default().with("https://mods.vintagestory.at/api/mod/betterfarming", 200, &mod_json(1, None, &[]));
+    let api = VintageStoryModDbApi::with_transport(&transport, false);
+
+    for alias in ["https://mods.vintagestory.at/betterfarming", "better%2Ffarming", "", "  ", "better farming"] {
+        assert!(matches!(api.get_mod_from_alias(alias).await, Err(ApiError::Unexpected(_))), "{:?} should be rejected", alias);
+    }
+    assert_eq!(transport.request_count(), 0);
+
+    assert_eq!(api.get_mod_from_alias("  BetterFarming\n").await?.mod_id, 1);
+    assert_eq!(transport.request_count(), 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mod_detail_cache() -> Result<(), ApiError> {
+    let detail = mod_json(1, None, &[]).replace(r#""urlalias":null"#, r#""urlalias":"betterfarming""#);
+    let transport = FakeTransport::default().with("https://mods.vintagestory.at/api/mod/1", 200, &detail);
+    let api = VintageStoryModDbApi::with_transport(&transport, true);
+
+    api.get_mod(1).await?;
+    assert_eq!(api.get_mod_from_alias("BetterFarming").await?.mod_id, 1);
+    assert_eq!(api.get_mod(1).await?.mod_id, 1);
+    assert_eq!(transport.request_count(), 1, "cached details should be reused by id and alias");
+
+    api.clear_all_caches();
+    api.get_mod(1).await?;
+    assert_eq!(transport.request_count(), 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cache_ttl() -> Result<(), ApiError> {
+    let transport = FakeTransport::default().with("https://mods.vintagestory.at/api/mods", 200, MODS);
+    let api = VintageStoryModDbApi::with_transport(&transport, true).with_cache_ttl(std::time::Duration::from_millis(50));
+
+    api.get_mods().await?;
+    api.get_mods().await?;
+    assert_eq!(transport.request_count(), 1);
+
+    std::thread::sleep(std::time::Duration::from_millis(80));
+    api.get_mods().await?;
+    assert_eq!(transport.request_count(), 2, "expired cache should be refetched");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_mods_by_author() -> Result<(), ApiError> {
+    let transport = FakeTransport::default()
+        .with("https://mods.vintagestory.at/api/mods", 200, MODS)
+        .with("https://mods.vintagestory.at/api/mods?text=&sortby=lastreleased&sortdir=d&side=&userid=42&mv=", 200, MODS);
+    let api = VintageStoryModDbApi::with_transport(&transport, true);
+
+    let alice = Author { userid: 42, name: Some(" alice ".to_string()) };
+    let ids: Vec<u32> = api.get_mods_by_author(&alice).await?.iter().map(|m| m.mod_id).collect();
+    assert_eq!(ids, vec![1, 3]);
+    assert!(api.get_mods_by_author(&Author { userid: 7, name: None }).await?.is_empty());
+    assert_eq!(api.get_mods_by_user_id(42).await?.len(), 3);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_search_mods_filtered() -> Result<(), ApiError> {
+    let url = "https://mods.vintagestory.at/api/mods?text=farm&sortby=downloads&sortdir=d&side=&userid=0&mv=&tagids%5B%5D=1&tagids%5B%5D=2";
+    let transport = FakeTransport::default()
+        .with(url, 200, MODS)
+        .with("https://mods.vintagestory.at/api/mods?text=farm&sortby=downloads&sortdir=d&side=&userid=0&mv=", 200, MODS);
+    let api = VintageStoryModDbApi::with_transport(&transport, false);
+    assert_eq!(api.search_mods_filtered("farm", &[1, 2], SortBy::Downloads, false).await?.len(), 3);
+    assert_eq!(api.search_mods_filtered("farm", &[], SortBy::Downloads, false).await?.len(), 3);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_search_mods_for_version() -> Result<(), ApiError> {
+    let url = "https://mods.vintagestory.at/api/mods?text=&sortby=lastreleased&sortdir=d&side=&userid=0&mv=-281470681677823";
+    let transport = FakeTransport::default().with(url, 200, MODS);
+    let api = VintageStoryModDbApi::with_transport(&transport, false);
+    let version = GameVersion { tag_id: -281470681677823, name: "v1.20.0".to_string(), color: "#CCCCCC".to_string() };
+    assert_eq!(api.search_mods_for_version("", &version, None, false).await?.len(), 3);
+    Ok(())
+}
+
+/// Answers with `status` for the first `failures` requests, then with `TAGS`.
+struct FlakyTransport {
+    failures: usize,
+    status: u16,
+    requests: std::sync::atomic::AtomicUsize,
+}
+
+impl Transport for FlakyTransport {
+    async fn get(&self, _url: &str) -> Result<(u16, String), TransportError> {
+        let n = self.requests.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        if n < self.failures { Ok((self.status, String::new())) } else { Ok((200, TAGS.to_string())) }
+    }
+}
+
+impl Transport for &FlakyTransport {
+    async fn get(&self, url: &str) -> Result<(u16, String), TransportError> {
+        (*self).get(url).await
+    }
+}
+
+#[test]
+fn test_retry_delay_saturates() {
+    use std::time::Duration;
+
+    let policy = RetryPolicy::new(u32::MAX);
+    assert_eq!(policy.delay(0), Duration::from_millis(500));
+    assert_eq!(policy.delay(2), Duration::from_secs(2));
+    assert_eq!(policy.delay(5_000), Duration::MAX);
+    assert_eq!(policy.delay(u32::MAX), Duration::MAX);
+    let huge = RetryPolicy { backoff_factor: f64::MAX, ..policy };
+    assert_eq!(huge.delay(3), Duration::MAX);
+    assert_eq!(RetryPolicy { base_delay: Duration::ZERO, ..huge }.delay(3), Duration::ZERO);
+}
+
+#[tokio::test]
+async fn test_retry_policy() -> Result<(), ApiError> {
+    use std::sync::atomic::Ordering;
+    use std::time::Duration;
+
+    let policy = RetryPolicy { max_retries: 2, base_delay: Duration::ZERO, backoff_factor: 2.0 };
+    let flaky = |failures, status| FlakyTransport { failures, status, requests: Default::default() };
+
+    let api = VintageStoryModDbApi::with_transport(flaky(2, 503), false).with_retry_policy(policy.clone());
+    assert_eq!(api.get_tags().await?.len(), 2);
+
+    let transport = flaky(3, 429);
+    let api = VintageStoryModDbApi::with_transport(&transport, false).with_retry_policy(policy.clone());
+    assert!(matches!(api.get_tags().await, Err(ApiError::HttpStatus { status: 429, .. })));
+    assert_eq!(transport.requests.load(Ordering::SeqCst), 3, "should give up after max_retries");
+
+    // Client errors aren't retried, and the default policy never retries
+    let transport = flaky(1, 404);
+    let api = VintageStoryModDbApi::with_transport(&transport, false).with_retry_policy(policy);
+    assert!(api.get_tags().await.is_err());
+    assert_eq!(transport.requests.load(Ordering::SeqCst), 1);
+    let transport = flaky(1, 503);
+    assert!(VintageStoryModDbApi::with_transport(&transport, false).get_tags().await.is_err());
+    assert_eq!(transport.requests.load(Ordering::SeqCst), 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rate_limit() -> Result<(), ApiError> {
+    let transport = FakeTransport::default().with("https://mods.vintagestory.at/api/tags", 200, TAGS);
+    let api = VintageStoryModDbApi::with_transport(&transport, false).with_rate_limit(20.0);
+    assert_eq!(api.config().requests_per_second, Some(20.0));
+
+    let start = std::time::Instant::now();
+    for _ in 0..5 {
+        api.get_tags().await?;
+    }
+    // The first request goes out immediately, the other four wait 50ms each
+    assert!(start.elapsed() >= std::time::Duration::from_millis(200));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rate_limit_clamped() -> Result<(), ApiError> {
+    let transport = FakeTransport::default().with("https://mods.vintagestory.at/api/tags", 200, TAGS);
+    let api = VintageStoryModDbApi::with_transport(&transport, false).with_rate_limit(1e-20);
+    assert_eq!(api.config().requests_per_second, Some(1.0 / 86_400.0));
+    api.get_tags().await?;
+    assert_eq!(VintageStoryModDbApi::with_transport(&transport, false).with_rate_limit(-1.0).config().requests_per_second, None);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_mods_detailed() -> Result<(), ApiError> {
+    let transport = catalog().with("https://mods.vintagestory.at/api/mod/4", 404, r#"{"statuscode":"404"}"#);
+    let api = VintageStoryModDbApi::with_transport(&transport, false);
+    let results = api.get_mods_detailed(&[3, 4, 1, 2], 2).await?;
+    assert_eq!(results.len(), 4);
+    assert_eq!(results[0].as_ref().unwrap().mod_id, 3);
+    assert!(matches!(results[1], Err(ApiError::NotFound { .. })));
+    assert_eq!(results[2].as_ref().unwrap().mod_id, 1);
+    assert_eq!(results[3].as_ref().unwrap().mod_id, 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_snapshot_round_trip() -> Result<(), ApiError> {
+    let transport = FakeTransport::default()
+        .with("https://mods.vintagestory.at/api/mods", 200, MODS)
+        .with("https://mods.vintagestory.at/api/authors", 200, r#"{"statuscode":"200","authors":[{"userid":7,"name":"Alice"}]}"#)
+        .with("https://mods.vintagestory.at/api/tags", 200, TAGS)
+        .with("https://mods.vintagestory.at/api/gameversions", 200, r##"{"statuscode":"200","gameversions":[{"tagid":-1,"name":"v1.20.0","color":"#CCCCCC"}]}"##);
+    let path = std::env::temp_dir().join(format!("vsmodapi-{}-snapshot.json", std::process::id()));
+    VintageStoryModDbApi::with_transport(&transport, false).export_snapshot(&path).await?;
+    assert_eq!(transport.request_count(), 4);
+
+    let offline = FakeTransport::default();
+    let api = VintageStoryModDbApi::with_transport(&offline, true);
+    let snapshot = api.import_snapshot(&path).await?;
+    assert_eq!(snapshot.version, CatalogSnapshot::VERSION);
+    assert_eq!(snapshot.tags[1].name, "QoL");
+    assert_eq!(snapshot.game_versions[0].name, "v1.20.0");
+    assert_eq!(api.get_mods().await?.len(), 3);
+    assert_eq!(api.get_authors().await?[0].userid, 7);
+    assert_eq!(offline.request_count(), 0);
+
+    std::fs::write(&path, r#"{"version":99,"mods":[],"authors":[]}"#)?;
+    assert!(matches!(api.import_snapshot(&path).await, Err(ApiError::Unexpected(_))));
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_mods_by_tag_name() -> Result<(), ApiError> {
+    let transport = FakeTransport::default()
+        .with("https://mods.vintagestory.at/api/tags", 200, TAGS)
+        .with("https://mods.vintagestory.at/api/mods?text=&sortby=lastreleased&sortdir=d&side=&userid=0&mv=&tagids%5B%5D=2", 200, MODS);
+    let api = VintageStoryModDbApi::with_transport(&transport, false);
+    assert_eq!(api.get_mods_by_tag_name(" qol ").await?.len(), 3);
+    assert!(matches!(api.get_mods_by_tag_name("technology").await, Err(ApiError::NotFound { .. })));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_search_name_unicode() -> Result<(), ApiError> {
+    let mods = MODS
+        .replace(r#""name":"Better Farming""#, r#""name":"Café Décor""#)
+        .replace(r#""name":"Crop Rotation""#, r#""name":"Ёлки и Палки""#)
+        .replace(r#""name":"Mod Manager""#, r#""name":"ちょっとした改良""#);
+    let transport = FakeTransport::default().with("https://mods.vintagestory.at/api/mods", 200, &mods);
+    let api = VintageStoryModDbApi::with_transport(&transport, true);
+    assert_eq!(api.search_name("cafe decor").await?[0].mod_id, 1);
+    assert_eq!(api.search_name("ЁЛКИ И ПАЛКИ").await?[0].mod_id, 2);
+    assert_eq!(api.search_name("ちょっと した 改良").await?[0].mod_id, 3);
+    assert!(api.search_name("cafe").await?.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_search_name_fuzzy() -> Result<(), ApiError> {
+    let transport = FakeTransport::default().with("https://mods.vintagestory.at/api/mods", 200, MODS);
+    let api = VintageStoryModDbApi::with_transport(&transport, true);
+
+    let results = api.search_name_fuzzy("Beter Farmng", 5).await?;
+    assert_eq!(results[0].0.mod_id, 1);
+    assert!(results[0].1 > 0.8 && results[0].1 < 1.0, "{}", results[0].1);
+
+    let results = api.search_name_fuzzy("crop", 5).await?;
+    assert_eq!(results.iter().map(|(m, _)| m.mod_id).collect::<Vec<_>>(), vec![2]);
+
+    assert_eq!(api.search_name_fuzzy("mod manager", 1).await?[0].1, 1.0);
+    assert_eq!(api.search_name_fuzzy("mod", 1).await?.len(), 1);
+    assert!(api.search_name_fuzzy("zzzz", 5).await?.is_empty());
+    assert!(api.search_name_fuzzy(" ", 5).await?.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_catalog_stats() -> Result<(), ApiError> {
+    let transport = FakeTransport::default().with("https://mods.vintagestory.at/api/mods", 200, MODS);
+    let api = VintageStoryModDbApi::with_transport(&transport, false);
+    let stats = api.catalog_stats().await?;
+    assert_eq!(stats.total_mods, 3);
+    assert_eq!(stats.total_downloads, 5550);
+    assert_eq!(stats.total_follows, 111);
+    assert_eq!(stats.total_comments, 51);
+    assert_eq!(stats.average_downloads, 1850.0);
+    assert_eq!(CatalogStats::from_mods(&[]), CatalogStats::default());
+    Ok(())
+}
+
+/// Code written against the trait, as an application would.
+async fn most_downloaded<C: ModDbClient>(client: &C) -> Result<Option<String>, ApiError> {
+    let mods = client.get_mods().await?;
+    Ok(mods.into_iter().max_by_key(|m| m.downloads).map(|m| m.name))
+}
+
+#[tokio::test]
+async fn test_mod_db_client_trait() -> Result<(), ApiError> {
+    let transport = catalog();
+    let api = VintageStoryModDbApi::with_transport(&transport, false);
+    assert_eq!(most_downloaded(&api).await?.as_deref(), Some("Mod Manager"));
+    assert_eq!(ModDbClient::get_mod(&api, 2).await?.mod_id, 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_tag_lookups() -> Result<(), ApiError> {
+    let transport = FakeTransport::default().with("https://mods.vintagestory.at/api/tags", 200, TAGS);
+    let api = VintageStoryModDbApi::with_transport(&transport, false);
+    assert_eq!(api.get_tag_by_id(2).await?.map(|t| t.name).as_deref(), Some("QoL"));
+    assert_eq!(api.get_tag_by_id(99).await?, None);
+    assert_eq!(api.get_tag_by_name("magic").await?.map(|t| t.tag_id), Some(1));
+    assert_eq!(api.get_tag_by_name("Technology").await?, None);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_tags_and_game_versions_cache() -> Result<(), ApiError> {
+    let transport = FakeTransport::default()
+        .with("https://mods.vintagestory.at/api/tags", 200, TAGS)
+        .with("https://mods.vintagestory.at/api/gameversions", 200, r##"{"statuscode":"200","gameversions":[{"tagid":-1,"name":"v1.20.0","color":"#CCCCCC"}]}"##);
+    let api = VintageStoryModDbApi::with_transport(&transport, true);
+    assert_eq!(api.get_tag_by_name("qol").await?.map(|t| t.tag_id), Some(2));
+    assert_eq!(api.get_tags().await?.len(), 2);
+    assert_eq!(api.get_game_versions().await?.len(), 1);
+    assert_eq!(api.get_game_versions().await?.len(), 1);
+    assert_eq!(transport.request_count(), 2, "tags and game versions should be cached");
+
+    assert!(api.refresh_tags_cache().await?);
+    assert_eq!(transport.request_count(), 3);
+    api.clear_all_caches();
+    api.get_tags().await?;
+    api.get_game_versions().await?;
+    assert_eq!(transport.request_count(), 5);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_author_by_id() -> Result<(), ApiError> {
+    let transport = FakeTransport::default()
+        .with("https://mods.vintagestory.at/api/authors", 200, r#"{"statuscode":"200","authors":[{"userid":7,"name":"Alice"},{"userid":8,"name":null}]}"#);
+    let api = VintageStoryModDbApi::with_transport(&transport, true);
+    assert_eq!(api.get_author_by_id(7).await?.and_then(|a| a.name).as_deref(), Some("Alice"));
+    assert_eq!(api.get_author_by_id(8).await?.map(|a| a.userid), Some(8));
+    assert_eq!(api.get_author_by_id(9).await?, None);
+    assert_eq!(transport.request_count(), 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_latest_comments() -> Result<(), ApiError> {
+    let comment = |id: u32, created: &str| format!(r#"{{"commentid":{id},"assetid":101,"userid":7,"text":"Hi","created":"{created}","lastmodified":"{created}"}}"#);
+    let body = format!(
+        r#"{{"statuscode":"200","comments":[{},{},{}]}}"#,
+        comment(1, "2024-01-05 10:00:00"), comment(2, "2024-03-01 09:00:00"), comment(3, "2023-12-31 23:59:59")
+    );
+    let transport = FakeTransport::default().with("https://mods.vintagestory.at/api/comments/101", 200, &body);
+    let api = VintageStoryModDbApi::with_transport(&transport, false);
+    let ids: Vec<u32> = api.get_latest_comments(101, 2).await?.iter().map(|c| c.comment_id).collect();
+    assert_eq!(ids, vec![2, 1]);
+    assert_eq!(api.get_latest_comments(101, 10).await?.len(), 3);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_latest_comments_parses_dates() -> Result<(), ApiError> {
+    let comment = |id: u32, created: &str| format!(r#"{{"commentid":{id},"assetid":101,"userid":7,"text":"Hi","created":"{created}","lastmodified":"{created}"}}"#);
+    let body = format!(
+        r#"{{"statuscode":"200","comments":[{},{},{},{},{}]}}"#,
+        comment(1, "2024-03-01 9:00:00"), comment(2, "unknown"), comment(3, "2024-03-01 10:00:00"),
+        comment(4, "2024-03-01 10:00:00"), comment(5, "2024-02-28 23:00:00")
+    );
+    let transport = FakeTransport::default().with("https://mods.vintagestory.at/api/comments/101", 200, &body);
+    let api = VintageStoryModDbApi::with_transport(&transport, false);
+    let ids: Vec<u32> = api.get_latest_comments(101, 10).await?.iter().map(|c| c.comment_id).collect();
+    assert_eq!(ids, vec![4, 3, 1, 5, 2]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_most_recent_release_ignores_list_order() -> Result<(), ApiError> {
+    let detail = mod_json(5, None, &[
+        (51, "1.0.0", "2023-01-01 00:00:00", &["v1.20.0"]),
+        (53, "1.2.0", "2024-06-01 00:00:00", &["v1.21.0"]),
+        (52, "1.1.0", "2023-09-01 00:00:00", &["v1.20.0"]),
+    ]);
+    let transport = FakeTransport::default()
+        .with("https://mods.vintagestory.at/api/mod/5", 200, &detail)
+        .with("https://mods.vintagestory.at/api/mod/6", 200, &mod_json(6, None, &[]));
+    let api = VintageStoryModDbApi::with_transport(&transport, false);
+
+    assert_eq!(api.get_most_recent_release_with_version(5, "v1.20.0").await?.release_id, 52);
+    assert_eq!(api.get_most_recent_release_with_version(5, "v1.19.0").await?.release_id, 53, "falls back to the newest release");
+    assert!(matches!(api.get_most_recent_release_with_version(6, "v1.20.0").await, Err(ApiError::Empty("releases"))));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mod_supports_version() -> Result<(), ApiError> {
+    let transport = catalog();
+    let api = VintageStoryModDbApi::with_transport(&transport, false);
+    assert!(api.mod_supports_version(2, "v1.20.0").await?);
+    assert!(api.mod_supports_version(2, "1.20.0").await?);
+    assert!(!api.mod_supports_version(1, "v1.20.0").await?);
+    assert!(!api.mod_supports_version(3, "v1.20.0").await?);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_recommended_release() -> Result<(), ApiError> {
+    let versions = r##"{"statuscode":"200","gameversions":[{"tagid":-1,"name":"v1.20.0","color":"#CCCCCC"},{"tagid":-2,"name":"v1.21.0-rc.1","color":"#CCCCCC"},{"tagid":-3,"name":"v1.19.8","color":"#CCCCCC"}]}"##;
+    let transport = catalog().with("https://mods.vintagestory.at/api/gameversions", 200, versions);
+    let api = VintageStoryModDbApi::with_transport(&transport, false);
+    assert_eq!(api.get_recommended_release(2).await?.map(|r| r.release_id), Some(21));
+    assert_eq!(api.get_recommended_release(1).await?, None);
+    assert_eq!(api.get_recommended_release(3).await?, None);
+    Ok(())
+}
+
+#[cfg(feature = "stream")]
+#[tokio::test]
+async fn test_stream_detailed_mods() -> Result<(), ApiError> {
+    use futures::StreamExt;
+
+    let transport = catalog();
+    let api = VintageStoryModDbApi::with_transport(&transport, false);
+    let mut ids: Vec<u32> = api.stream_detailed_mods(2).map(|m| m.map(|m| m.mod_id)).collect::<Vec<_>>().await.into_iter().collect::<Result<_, _>>()?;
+    ids.sort();
+    assert_eq!(ids, vec![1, 2, 3]);
+    assert_eq!(transport.request_count(), 4);
+
+    let failing = VintageStoryModDbApi::with_transport(FakeTransport::default(), false);
+    let items: Vec<_> = failing.stream_detailed_mods(2).collect().await;
+    assert!(matches!(items.as_slice(), [Err(_)]));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_mod_or_stub() -> Result<(), ApiError> {
+    let transport = FakeTransport::default()
+        .with("https://mods.vintagestory.at/api/mods", 200, MODS)
+        .with("https://mods.vintagestory.at/api/mod/1", 503, "<html>Service Unavailable</html>")
+        .with("https://mods.vintagestory.at/api/mod/2", 404, r#"{"statuscode":"404"}"#)
+        .with("https://mods.vintagestory.at/api/mod/9", 503, "<html>Service Unavailable</html>");
+    let api = VintageStoryModDbApi::with_transport(&transport, true);
+    api.get_mods().await?;
+
+    let stub = api.get_mod_or_stub(1).await?;
+    assert!(stub.is_stub);
+    assert_eq!((stub.mod_id, stub.name.as_str()), (1, "Better Farming"));
+    assert!(stub.releases.is_empty());
+
+    assert!(matches!(api.get_mod_or_stub(2).await, Err(ApiError::NotFound { .. })));
+    assert!(matches!(api.get_mod_or_stub(9).await, Err(ApiError::HttpStatus { status: 503, .. })));
+    Ok(())
+}
+
+#[cfg(feature = "stream")]
+#[tokio::test]
+async fn test_all_comments_stream() {
+    use futures::StreamExt;
+
+    let comment = |id: u32, asset_id: u32| format!(r#"{{"commentid":{id},"assetid":{asset_id},"userid":7,"text":"Hi","created":"2024-01-01 00:00:00","lastmodified":"2024-01-01 00:00:00"}}"#);
+    let transport = FakeTransport::default()
+        .with("https://mods.vintagestory.at/api/mods", 200, MODS)
+        .with("https://mods.vintagestory.at/api/comments/101", 200, &format!(r#"{{"statuscode":"200","comments":[{},{}]}}"#, comment(1, 101), comment(2, 101)))
+        .with("https://mods.vintagestory.at/api/comments/102", 200, &format!(r#"{{"statuscode":"200","comments":[{}]}}"#, comment(3, 102)))
+        .with("https://mods.vintagestory.at/api/comments/103", 503, "<html>Service Unavailable</html>");
+    let api = VintageStoryModDbApi::with_transport(&transport, false);
+
+    let items: Vec<_> = api.all_comments_stream(2).collect().await;
+    let mut pairs: Vec<(u32, u32)> = items.iter().filter_map(|item| item.as_ref().ok()).map(|(asset_id, c)| (*asset_id, c.comment_id)).collect();
+    pairs.sort();
+    assert_eq!(pairs, vec![(101, 1), (101, 2), (102, 3)]);
+    let errors: Vec<_> = items.iter().filter_map(|item| item.as_ref().err()).collect();
+    assert!(matches!(errors.as_slice(), [ApiError::HttpStatus { status: 503, .. }]), "{:?}", errors);
+}
+
+fn simple_mod_json(mod_id: u32, mod_id_strs: &[&str], tags: &[&str], side: &str, mod_type: &str) -> String {
+    let quote = |items: &[&str]| items.iter().map(|s| format!("\"{}\"", s)).collect::<Vec<_>>().join(",");
+    format!(
+        r#"{{"modid":{mod_id},"assetid":{asset_id},"downloads":0,"follows":0,"trendingpoints":0,"comments":0,"name":"Mod {mod_id}","summary":null,"modidstrs":[{ids}],"author":"Alice","urlalias":null,"side":"{side}","type":"{mod_type}","logo":null,"tags":[{tags}],"lastreleased":"2024-01-01 00:00:00"}}"#,
+        asset_id = mod_id + 100,
+        ids = quote(mod_id_strs),
+        tags = quote(tags),
+    )
+}
+
+fn mods_list(mods: &[String]) -> FakeTransport {
+    let body = format!(r#"{{"statuscode":"200","mods":[{}]}}"#, mods.join(","));
+    FakeTransport::default().with("https://mods.vintagestory.at/api/mods", 200, &body)
+}
+
+#[tokio::test]
+async fn test_find_modid_collisions() -> Result<(), ApiError> {
+    let transport = mods_list(&[
+        simple_mod_json(1, &["carryon"], &[], "both", "mod"),
+        simple_mod_json(2, &["CarryOn"], &[], "both", "mod"),
+        simple_mod_json(3, &["packrat", "packrat"], &[], "both", "mod"),
+    ]);
+    let api = VintageStoryModDbApi::with_transport(&transport, false);
+    let collisions = api.find_modid_collisions().await?;
+    assert_eq!(collisions.keys().collect::<Vec<_>>(), vec!["carryon"]);
+    assert_eq!(collisions["carryon"].iter().map(|m| m.mod_id).collect::<Vec<_>>(), vec![1, 2]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_normalized_tags() -> Result<(), ApiError> {
+    let transport = mods_list(&[
+        simple_mod_json(1, &[], &["QoL", "Farming"], "both", "mod"),
+        simple_mod_json(2, &[], &["qol"], "both", "mod"),
+        simple_mod_json(3, &[], &[" Qol "], "both", "mod"),
+    ]);
+    let api = VintageStoryModDbApi::with_transport(&transport, true);
+    let counts = api.normalized_tag_counts().await?;
+    assert_eq!(counts, HashMap::from([("qol".to_string(), 3), ("farming".to_string(), 1)]));
+    let variants = api.normalized_tag_variants().await?;
+    assert_eq!(variants.len(), 2);
+    assert_eq!(variants["qol"].iter().collect::<Vec<_>>(), vec!["QoL", "Qol", "qol"]);
+    assert_eq!(transport.request_count(), 1);
+    Ok(())
+}
+
+#[cfg(feature = "regex")]
+#[tokio::test]
+async fn test_search_summary_regex() -> Result<(), ApiError> {
+    let transport = FakeTransport::default().with("https://mods.vintagestory.at/api/mods", 200, MODS);
+    let api = VintageStoryModDbApi::with_transport(&transport, true);
+    let ids: Vec<u32> = api.search_summary_regex(r"(?i)^(more|improves) ").await?.iter().map(|m| m.mod_id).collect();
+    assert_eq!(ids, vec![1, 2]);
+    assert!(api.search_summary_regex(r"^\d+$").await?.is_empty());
+    let err = api.search_summary_regex("(unclosed").await.unwrap_err();
+    assert!(matches!(err, ApiError::Unexpected(message) if message.contains("(unclosed")));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_distinct_sides_and_mod_types() -> Result<(), ApiError> {
+    let transport = FakeTransport::default().with("https://mods.vintagestory.at/api/mods", 200, MODS);
+    let api = VintageStoryModDbApi::with_transport(&transport, true);
+    assert_eq!(api.distinct_sides().await?, vec!["both", "client", "server"]);
+    assert_eq!(api.distinct_mod_types().await?, vec!["externaltool", "mod"]);
+
+    let untyped = r#"{"modid":4,"assetid":104,"downloads":0,"follows":0,"trendingpoints":0,"comments":0,"name":"Untyped","summary":null,"modidstrs":[],"author":"Alice","urlalias":null,"logo":null,"tags":[],"lastreleased":"2024-01-01 00:00:00"}"#;
+    let transport = mods_list(&[
+        simple_mod_json(1, &[], &[], "server", "mod"),
+        simple_mod_json(2, &[], &[], "", " "),
+        simple_mod_json(3, &[], &[], "server", "mod"),
+        untyped.to_string(),
+    ]);
+    let api = VintageStoryModDbApi::with_transport(&transport, true);
+    assert_eq!(api.distinct_sides().await?, vec!["server"]);
+    assert_eq!(api.distinct_mod_types().await?, vec!["mod"]);
+    Ok(())
+}
+
+#[cfg(feature = "metrics")]
+#[test]
+fn test_request_metrics() {
+    use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+
+    let recorder = DebuggingRecorder::new();
+    let snapshotter = recorder.snapshotter();
+    let transport = catalog().with("https://mods.vintagestory.at/api/mod/9", 404, r#"{"statuscode":"404"}"#);
+    let api = VintageStoryModDbApi::with_transport(&transport, false);
+    metrics::with_local_recorder(&recorder, || {
+        let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+        runtime.block_on(async {
+            api.get_mod(1).await.unwrap();
+            assert!(matches!(api.get_mod(9).await, Err(ApiError::NotFound { .. })));
+        });
+    });
+
+    let mut requests = Vec::new();
+    let mut errors = 0;
+    for (key, _, _, value) in snapshotter.snapshot().into_vec() {
+        let labels: Vec<(String, String)> = key.key().labels().map(|l| (l.key().to_string(), l.value().to_string())).collect();
+        match (key.key().name(), value) {
+            ("vintagestory_api_requests_total", DebugValue::Counter(count)) => requests.push((labels, count)),
+            ("vintagestory_api_errors_total", DebugValue::Counter(count)) => errors += count,
+            _ => {}
+        }
+    }
+    requests.sort();
+    let label = |status: &str| vec![("endpoint".to_string(), "/mod".to_string()), ("status".to_string(), status.to_string())];
+    assert_eq!(requests, vec![(label("200"), 1), (label("404"), 1)]);
+    assert_eq!(errors, 1);
+}
+
+#[tokio::test]
+async fn test_fetch_details_skips_missing_mods() -> Result<(), ApiError> {
+    // Mod 1 was removed after the mods list was fetched
+    let transport = catalog().with("https://mods.vintagestory.at/api/mod/1", 404, r#"{"statuscode":"404"}"#);
+    let api = VintageStoryModDbApi::with_transport(&transport, true);
+    assert!(api.get_documented_mods(2).await?.is_empty());
+    assert_eq!(api.get_outdated_mods("v1.20.0", 2).await?.iter().map(|m| m.mod_id).collect::<Vec<_>>(), vec![3]);
+    assert_eq!(api.version_adoption("v1.20.0", 2).await?, 0.5);
+    assert_eq!(api.mods_per_game_version(2).await?.into_iter().collect::<Vec<_>>(), vec![("v1.19.8".to_string(), 1), ("v1.20.0".to_string(), 1)]);
+
+    let transport = catalog().with("https://mods.vintagestory.at/api/mod/1", 503, "<html>Service Unavailable</html>");
+    let api = VintageStoryModDbApi::with_transport(&transport, true).with_retry_policy(RetryPolicy::new(0));
+    assert!(matches!(api.get_documented_mods(2).await, Err(ApiError::HttpStatus { status: 503, .. })));
+    Ok(())
+}